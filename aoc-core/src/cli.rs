@@ -0,0 +1,279 @@
+//! Command line interface shared by the binaries of every day.
+
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use crate::config::Config;
+use crate::input::expand_inputs;
+use crate::report::{Format, ReportWriter};
+use crate::timings::{self, measure, Measurement, Timings};
+use crate::{read_input, solve_parsed, Error, Solution};
+
+/// Options every day accepts. Flatten this into the `Args` of a binary.
+#[derive(clap::Args, Debug)]
+pub struct CommonArgs {
+    /// Puzzle inputs: files, directories of `*.txt` files, or `-` for stdin. Defaults to the
+    /// `input` of the day in `aoc.toml`
+    #[arg(
+        short,
+        long = "input-file",
+        num_args = 1..,
+        value_hint(clap::ValueHint::AnyPath)
+    )]
+    pub input_files: Vec<PathBuf>,
+    /// Output format of the answers. Defaults to the `format` in `aoc.toml`, or `text`
+    #[arg(short, long, value_enum)]
+    pub format: Option<Format>,
+    /// Report wall time and peak heap usage of every phase on stderr
+    #[arg(long)]
+    pub timings: bool,
+}
+
+/// The options of a binary, with what the command line leaves open taken from `aoc.toml`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Settings {
+    pub input_files: Vec<PathBuf>,
+    pub format: Format,
+    pub timings: bool,
+}
+
+impl CommonArgs {
+    /// Fills in the options not given on the command line from `config`, for day `day`, which
+    /// has no options of its own.
+    pub fn resolve(&self, day: u8, config: &Config) -> Result<Settings, String> {
+        self.resolve_with(day, config, &[])
+    }
+
+    /// Like [`resolve`](CommonArgs::resolve), for a day that reads the `options` of its own
+    /// from `config`, usually its [`Solution::OPTIONS`].
+    pub fn resolve_with(
+        &self,
+        day: u8,
+        config: &Config,
+        options: &[&str],
+    ) -> Result<Settings, String> {
+        config.check_options(day, options)?;
+        let input_files = if self.input_files.is_empty() {
+            config.inputs(day)
+        } else {
+            self.input_files.clone()
+        };
+        if input_files.is_empty() {
+            return Err(format!(
+                "no input given, pass --input-file or set `input` under [day{day:02}] in {}",
+                crate::config::FILE_NAME
+            ));
+        }
+
+        Ok(Settings {
+            input_files,
+            format: self.format.or(config.format()).unwrap_or_default(),
+            timings: self.timings,
+        })
+    }
+}
+
+/// Solves both parts of `S` for every input and prints the answers, using `aoc.toml` for the
+/// options not given in `args`. A failing input is reported on stderr without stopping the
+/// others.
+pub fn run<S: Solution>(args: &CommonArgs) -> ExitCode {
+    let config = match Config::find() {
+        Ok(config) => config,
+        Err(error) => return exit_code(Err(error)),
+    };
+
+    match args.resolve(S::DAY, &config) {
+        Ok(settings) => run_with::<S>(&settings),
+        Err(reason) => {
+            eprintln!("error: {reason}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Like [`run`], with the options already resolved.
+pub fn run_with<S: Solution>(args: &Settings) -> ExitCode {
+    run_inputs::<S, _>(args, None::<fn(&S::Input) -> Result<(), Error>>)
+}
+
+/// Like [`run_with`], calling `show` on every parsed input before its answers are printed, for
+/// binaries that can display an input. Every input is read and parsed once, so `-` works here
+/// too.
+pub fn run_showing<S: Solution>(
+    args: &Settings,
+    show: impl FnMut(&S::Input) -> Result<(), Error>,
+) -> ExitCode {
+    run_inputs::<S, _>(args, Some(show))
+}
+
+fn run_inputs<S, F>(args: &Settings, mut show: Option<F>) -> ExitCode
+where
+    S: Solution,
+    F: FnMut(&S::Input) -> Result<(), Error>,
+{
+    let inputs = match expand_inputs(&args.input_files) {
+        Ok(inputs) => inputs,
+        Err(error) => return exit_code(Err(error)),
+    };
+
+    let mut writer = ReportWriter::new(io::stdout().lock(), args.format);
+    let mut failed = false;
+
+    for input in &inputs {
+        if let Err(error) = run_input::<S>(input, &mut writer, args.timings, show.as_mut()) {
+            failed = true;
+            // I/O errors already name the file
+            if inputs.len() > 1 && !matches!(error, Error::Io { .. }) {
+                eprintln!("error: {}: {error}", input.display());
+            } else {
+                eprintln!("error: {error}");
+            }
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn run_input<S: Solution>(
+    input: &Path,
+    writer: &mut ReportWriter<impl Write>,
+    timings: bool,
+    show: Option<&mut impl FnMut(&S::Input) -> Result<(), Error>>,
+) -> Result<(), Error> {
+    let (text, read) = measure(|| read_input(input));
+    let text = text?;
+    let (parsed, parse) = measure(|| S::parse(&text));
+    let parsed = parsed?;
+    if let Some(show) = show {
+        show(&parsed)?;
+    }
+    let answers = solve_parsed::<S>(&parsed, parse)?;
+
+    let name = input.display().to_string();
+    writer.write(&name, &answers).map_err(Error::Output)?;
+
+    if timings {
+        eprintln!("Timings for {name}");
+        print_timings(read, &answers.timings).map_err(Error::Output)?;
+    }
+
+    Ok(())
+}
+
+/// Prints the timings table to stderr, so that it never mixes with machine readable answers.
+pub fn print_timings(read: Measurement, timings: &Timings) -> io::Result<()> {
+    timings::write_table(
+        io::stderr().lock(),
+        &[
+            ("read", read),
+            ("parse", timings.parse),
+            ("part 1", timings.part_1),
+            ("part 2", timings.part_2),
+        ],
+    )
+}
+
+/// Turns the outcome of a binary into its exit code, reporting errors on stderr.
+pub fn exit_code(result: Result<(), Error>) -> ExitCode {
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::Summing;
+    use clap::{CommandFactory, Parser};
+
+    #[derive(Parser, Debug)]
+    struct Args {
+        #[command(flatten)]
+        common: CommonArgs,
+    }
+
+    #[test]
+    fn test_flattened_args() {
+        Args::command().debug_assert();
+
+        let args = Args::parse_from(["day01", "-i", "input.txt", "--format", "json"]);
+        assert_eq!(args.common.input_files, vec![PathBuf::from("input.txt")]);
+        assert_eq!(args.common.format, Some(Format::Json));
+
+        let args = Args::parse_from(["day01", "-i", "a.txt", "b.txt", "-i", "-"]);
+        assert_eq!(args.common.input_files.len(), 3);
+
+        let args = Args::parse_from(["day01"]);
+        assert!(args.common.input_files.is_empty());
+        assert_eq!(args.common.format, None);
+    }
+
+    #[test]
+    fn test_run_showing() {
+        let path = std::env::temp_dir().join(format!("aoc-core-showing-{}", std::process::id()));
+        std::fs::write(&path, "1 2 3").unwrap();
+        let settings = Settings {
+            input_files: vec![path.clone(), path.clone()],
+            format: Format::Csv,
+            timings: false,
+        };
+
+        let mut shown = vec![];
+        let code = run_showing::<Summing>(&settings, |numbers| {
+            shown.push(numbers.clone());
+            Ok(())
+        });
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(code, ExitCode::SUCCESS);
+        assert_eq!(shown, [vec![1, 2, 3], vec![1, 2, 3]]);
+    }
+
+    #[test]
+    fn test_resolve() {
+        let config = Config::parse(
+            "format = \"csv\"\n[day01]\ninput = \"in.txt\"",
+            Path::new(""),
+        );
+        let config = config.unwrap();
+
+        let settings = Args::parse_from(["day01"]).common.resolve(1, &config);
+        assert_eq!(
+            settings,
+            Ok(Settings {
+                input_files: vec![PathBuf::from("in.txt")],
+                format: Format::Csv,
+                timings: false,
+            })
+        );
+
+        let args = Args::parse_from(["day01", "-i", "other.txt", "-f", "text", "--timings"]);
+        let settings = args.common.resolve(1, &config).unwrap();
+        assert_eq!(settings.input_files, vec![PathBuf::from("other.txt")]);
+        assert_eq!(settings.format, Format::Text);
+
+        assert!(Args::parse_from(["day02"])
+            .common
+            .resolve(2, &config)
+            .is_err());
+        let settings = Args::parse_from(["day02", "-i", "-"])
+            .common
+            .resolve(2, &config);
+        assert_eq!(settings.unwrap().format, Format::Csv);
+
+        let config = Config::parse("[day01]\ninput = \"in.txt\"\nverbose = true", Path::new(""));
+        let config = config.unwrap();
+        let args = Args::parse_from(["day01"]);
+        assert!(args.common.resolve(1, &config).is_err());
+        assert!(args.common.resolve_with(1, &config, &["verbose"]).is_ok());
+    }
+}
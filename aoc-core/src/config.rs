@@ -0,0 +1,293 @@
+//! Defaults for the options of every binary and the runner, read from `aoc.toml`.
+//!
+//! The file is looked up in the current directory and then in its ancestors. Options given on
+//! the command line win over the ones in the file.
+//!
+//! ```toml
+//! # Output format when --format is not given
+//! format = "text"
+//!
+//! [day06]
+//! # Inputs when --input-file is not given: a path or a list of them, relative to aoc.toml
+//! input = "day06/input.txt"
+//! # Options of the day's binary, by their long name
+//! display-solution = true
+//!
+//! [site]
+//! # File holding the session cookie for adventofcode.com, relative to aoc.toml. The
+//! # AOC_SESSION environment variable wins over it
+//! session-file = ".aoc-session"
+//! ```
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::input::STDIN;
+use crate::report::Format;
+use crate::Error;
+
+pub const FILE_NAME: &str = "aoc.toml";
+
+/// The contents of an `aoc.toml`, empty when there is none.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+pub struct Config {
+    /// Directory of the file, which relative paths in it start from.
+    #[serde(skip)]
+    dir: PathBuf,
+    format: Option<Format>,
+    #[serde(default)]
+    site: SiteConfig,
+    #[serde(flatten)]
+    days: BTreeMap<String, DayConfig>,
+}
+
+/// The `[site]` table: how the runner talks to the Advent of Code website.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct SiteConfig {
+    /// Where the website is, without a trailing slash.
+    pub base_url: Option<String>,
+    /// File holding the session cookie.
+    pub session_file: Option<PathBuf>,
+}
+
+/// The `[dayNN]` table of a day.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+struct DayConfig {
+    #[serde(default)]
+    input: Inputs,
+    #[serde(flatten)]
+    options: toml::Table,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
+enum Inputs {
+    One(PathBuf),
+    Many(Vec<PathBuf>),
+}
+
+impl Default for Inputs {
+    fn default() -> Self {
+        Inputs::Many(vec![])
+    }
+}
+
+impl Config {
+    /// Parses the contents of an `aoc.toml`. Relative inputs are taken relative to `base`.
+    pub fn parse(text: &str, base: &Path) -> Result<Config, String> {
+        let mut config: Config = toml::from_str(text).map_err(|e| e.message().to_owned())?;
+        config.dir = base.to_path_buf();
+
+        for (name, day) in &mut config.days {
+            let number = name
+                .strip_prefix("day")
+                .filter(|number| number.len() == 2)
+                .and_then(|number| number.parse::<u8>().ok());
+            if !number.is_some_and(|number| (1..=25).contains(&number)) {
+                return Err(format!(
+                    "unknown key `{name}`, expected `format`, `[site]` or `[dayNN]`"
+                ));
+            }
+
+            let inputs = match &mut day.input {
+                Inputs::One(input) => std::slice::from_mut(input),
+                Inputs::Many(inputs) => inputs.as_mut_slice(),
+            };
+            for input in inputs {
+                if input.is_relative() && input.as_path() != Path::new(STDIN) {
+                    *input = base.join(&*input);
+                }
+            }
+        }
+
+        if let Some(session_file) = &mut config.site.session_file {
+            *session_file = base.join(&*session_file);
+        }
+
+        Ok(config)
+    }
+
+    /// Reads the `aoc.toml` at `path`.
+    pub fn load(path: &Path) -> Result<Config, Error> {
+        let text = fs::read_to_string(path).map_err(|source| Error::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        let base = path.parent().unwrap_or(Path::new(""));
+
+        Config::parse(&text, base).map_err(|reason| Error::Config {
+            path: path.to_path_buf(),
+            reason,
+        })
+    }
+
+    /// Reads the `aoc.toml` in the current directory or the closest ancestor that has one. Paths
+    /// in it stay relative to the current directory.
+    pub fn find() -> Result<Config, Error> {
+        let depth = env::current_dir().map_or(1, |dir| dir.ancestors().count());
+        let mut base = PathBuf::new();
+
+        for _ in 0..depth {
+            let path = base.join(FILE_NAME);
+            if path.is_file() {
+                return Config::load(&path);
+            }
+            base.push("..");
+        }
+
+        Ok(Config::default())
+    }
+
+    /// The directory the file is in, relative to the current directory when it was found by
+    /// [`find`](Config::find). Empty, so the current directory, when there is no file. This is
+    /// the root of the workspace, which the paths of the days start from.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn format(&self) -> Option<Format> {
+        self.format
+    }
+
+    pub fn site(&self) -> &SiteConfig {
+        &self.site
+    }
+
+    /// Inputs of `day`, empty when the file does not name any.
+    pub fn inputs(&self, day: u8) -> Vec<PathBuf> {
+        match self.day(day).map(|day| &day.input) {
+            Some(Inputs::One(input)) => vec![input.clone()],
+            Some(Inputs::Many(inputs)) => inputs.clone(),
+            None => vec![],
+        }
+    }
+
+    /// The on/off option `name` of `day`, `None` when it is not set.
+    pub fn flag(&self, day: u8, name: &str) -> Result<Option<bool>, String> {
+        let Some(value) = self.day(day).and_then(|day| day.options.get(name)) else {
+            return Ok(None);
+        };
+
+        value
+            .as_bool()
+            .map(Some)
+            .ok_or_else(|| format!("`{name}` of day{day:02} should be true or false"))
+    }
+
+    /// Checks that `day` sets no options but `input` and the ones in `known`, so that a
+    /// misspelt option is reported instead of ignored.
+    pub fn check_options(&self, day: u8, known: &[&str]) -> Result<(), String> {
+        let Some(config) = self.day(day) else {
+            return Ok(());
+        };
+        let Some(unknown) = config
+            .options
+            .keys()
+            .find(|name| !known.contains(&name.as_str()))
+        else {
+            return Ok(());
+        };
+
+        let expected: Vec<String> = ["input"]
+            .iter()
+            .chain(known)
+            .map(|name| format!("`{name}`"))
+            .collect();
+        Err(format!(
+            "unknown option `{unknown}` under [day{day:02}] in {FILE_NAME}, expected {}",
+            expected.join(" or ")
+        ))
+    }
+
+    fn day(&self, day: u8) -> Option<&DayConfig> {
+        self.days.get(&format!("day{day:02}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = r#"
+format = "json"
+
+[day01]
+input = "day01/input.txt"
+
+[day06]
+input = ["a.txt", "/tmp/b.txt", "-"]
+display-solution = true
+colour = "yes"
+
+[site]
+session-file = ".aoc-session"
+"#;
+
+    #[test]
+    fn test_parse() {
+        let config = Config::parse(CONFIG, Path::new("..")).unwrap();
+
+        assert_eq!(config.dir(), Path::new(".."));
+        assert_eq!(config.format(), Some(Format::Json));
+        assert_eq!(config.inputs(1), vec![PathBuf::from("../day01/input.txt")]);
+        assert_eq!(
+            config.inputs(6),
+            vec![
+                PathBuf::from("../a.txt"),
+                PathBuf::from("/tmp/b.txt"),
+                PathBuf::from("-")
+            ]
+        );
+        assert!(config.inputs(2).is_empty());
+
+        assert_eq!(config.flag(6, "display-solution"), Ok(Some(true)));
+        assert_eq!(config.flag(6, "missing"), Ok(None));
+        assert_eq!(config.flag(2, "display-solution"), Ok(None));
+        assert!(config.flag(6, "colour").is_err());
+
+        assert_eq!(config.check_options(1, &[]), Ok(()));
+        assert_eq!(config.check_options(2, &[]), Ok(()));
+        assert_eq!(
+            config.check_options(6, &["display-solution"]),
+            Err(String::from(
+                "unknown option `colour` under [day06] in aoc.toml, expected `input` or \
+                 `display-solution`"
+            ))
+        );
+
+        assert_eq!(config.site().base_url, None);
+        assert_eq!(
+            config.site().session_file,
+            Some(PathBuf::from("../.aoc-session"))
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(Config::parse("", Path::new("")), Ok(Config::default()));
+        assert!(Config::parse("format = \"xml\"", Path::new("")).is_err());
+        assert!(Config::parse("[day6]\ninput = \"a.txt\"", Path::new("")).is_err());
+        assert!(Config::parse("[day26]", Path::new("")).is_err());
+        assert!(Config::parse("[day01]\ninput = 1", Path::new("")).is_err());
+        assert!(Config::parse("[site]\nsession = \"abc\"", Path::new("")).is_err());
+    }
+
+    #[test]
+    fn test_load_reports_path() {
+        let dir = env::temp_dir().join(format!("aoc-core-config-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join(FILE_NAME), "[day01]\ninput = \"in.txt\"\n[days]\n").unwrap();
+        let invalid = Config::load(&dir.join(FILE_NAME));
+        fs::write(dir.join(FILE_NAME), "[day01]\ninput = \"in.txt\"\n").unwrap();
+        let valid = Config::load(&dir.join(FILE_NAME));
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(matches!(invalid, Err(Error::Config { path, .. }) if path == dir.join(FILE_NAME)));
+        assert_eq!(valid.unwrap().inputs(1), vec![dir.join("in.txt")]);
+    }
+}
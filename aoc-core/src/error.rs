@@ -0,0 +1,144 @@
+use std::error;
+use std::fmt::{self, Display, Formatter};
+use std::io;
+use std::path::PathBuf;
+
+/// Puzzle input that could not be parsed, with the location of the problem.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// The offending text, empty when something is missing at the end of the line.
+    pub text: String,
+    pub reason: String,
+}
+
+impl ParseError {
+    pub fn new(
+        line: usize,
+        column: usize,
+        text: impl Into<String>,
+        reason: impl Into<String>,
+    ) -> ParseError {
+        ParseError {
+            line,
+            column,
+            text: text.into(),
+            reason: reason.into(),
+        }
+    }
+
+    /// Error about `token`, which has to be a slice of `line`. The column is derived from the
+    /// position of `token` within `line`.
+    pub fn at(line_number: usize, line: &str, token: &str, reason: impl Into<String>) -> Self {
+        let offset = (token.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+        debug_assert!(offset <= line.len(), "token is not a slice of line");
+
+        let column = line
+            .get(..offset)
+            .map_or(1, |prefix| prefix.chars().count() + 1);
+
+        ParseError::new(line_number, column, token, reason)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.reason
+        )?;
+
+        if self.text.is_empty() {
+            write!(f, " at end of line")
+        } else {
+            write!(f, " at {:?}", self.text)
+        }
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// Everything that can go wrong while solving a day.
+#[derive(Debug)]
+pub enum Error {
+    /// The puzzle input could not be read.
+    Io { path: PathBuf, source: io::Error },
+    /// The answers could not be written.
+    Output(io::Error),
+    /// `aoc.toml` is malformed.
+    Config { path: PathBuf, reason: String },
+    /// The puzzle input is malformed.
+    Parse(ParseError),
+    /// The input parsed, but does not describe a puzzle that can be solved.
+    Unsolvable(String),
+    /// A number computed on the way to an answer does not fit in an [`Int`](crate::num::Int).
+    Overflow(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io { path, source } => write!(f, "unable to read {}: {source}", path.display()),
+            Error::Output(source) => write!(f, "unable to write output: {source}"),
+            Error::Config { path, reason } => write!(f, "invalid {}: {reason}", path.display()),
+            Error::Parse(error) => write!(f, "invalid puzzle input, {error}"),
+            Error::Unsolvable(reason) => write!(f, "puzzle cannot be solved: {reason}"),
+            Error::Overflow(what) => {
+                write!(f, "{what} does not fit in {} bits", crate::num::Int::BITS)?;
+                if cfg!(not(feature = "wide")) {
+                    write!(f, ", try again with `--features wide`")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } | Error::Output(source) => Some(source),
+            Error::Parse(error) => Some(error),
+            Error::Config { .. } | Error::Unsolvable(_) | Error::Overflow(_) => None,
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(error: ParseError) -> Self {
+        Error::Parse(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_at_derives_column() {
+        let line = "12 ab 34";
+        let error = ParseError::at(3, line, &line[3..5], "expected a number");
+
+        assert_eq!(error, ParseError::new(3, 4, "ab", "expected a number"));
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 4: expected a number at \"ab\""
+        );
+    }
+
+    #[test]
+    fn test_at_end_of_line() {
+        let line = "12 ";
+        let error = ParseError::at(1, line, &line[line.len()..], "expected a number");
+
+        assert_eq!(error.column, 4);
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 4: expected a number at end of line"
+        );
+    }
+}
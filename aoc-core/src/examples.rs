@@ -0,0 +1,217 @@
+//! Puzzle examples taken from a day's `readme.md`, so they are written down only once.
+//!
+//! An example is a fenced code block directly preceded by a marker comment with the expected
+//! answers. Parts without an expected answer are not checked.
+//!
+//! ````markdown
+//! <!-- example part1: 11, part2: 31 -->
+//! ```
+//! 3   4
+//! 4   3
+//! ```
+//! ````
+
+use crate::{Error, ParseError, Solution};
+
+const MARKER_START: &str = "<!-- example";
+const MARKER_END: &str = "-->";
+const FENCE: &str = "```";
+
+/// A marked example block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    /// 1-based line number of the marker.
+    pub line: usize,
+    pub input: String,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+/// Finds all marked examples in `readme`.
+pub fn parse_examples(readme: &str) -> Result<Vec<Example>, ParseError> {
+    let mut examples = vec![];
+    let mut lines = readme.lines().enumerate();
+
+    while let Some((index, line)) = lines.next() {
+        let Some(marker) = line.trim().strip_prefix(MARKER_START) else {
+            continue;
+        };
+        let line_number = index + 1;
+
+        let Some(answers) = marker.strip_suffix(MARKER_END) else {
+            return Err(ParseError::at(line_number, line, "", "expected `-->`"));
+        };
+        let mut example = Example {
+            line: line_number,
+            input: String::new(),
+            part_1: None,
+            part_2: None,
+        };
+
+        for answer in answers.split(',').filter(|a| !a.trim().is_empty()) {
+            let (part, value) = answer.split_once(':').ok_or_else(|| {
+                ParseError::at(line_number, line, answer.trim(), "expected `partN: answer`")
+            })?;
+            let value = Some(value.trim().to_owned());
+
+            match part.trim() {
+                "part1" => example.part_1 = value,
+                "part2" => example.part_2 = value,
+                other => {
+                    return Err(ParseError::at(line_number, line, other, "unknown part"));
+                }
+            }
+        }
+
+        match lines.next() {
+            Some((_, fence)) if fence.trim_start().starts_with(FENCE) => {}
+            _ => {
+                return Err(ParseError::new(
+                    line_number + 1,
+                    1,
+                    "",
+                    "expected a fenced code block after the example marker",
+                ))
+            }
+        }
+
+        let mut input = vec![];
+        loop {
+            match lines.next() {
+                Some((_, line)) if line.trim_start().starts_with(FENCE) => break,
+                Some((_, line)) => input.push(line),
+                None => {
+                    return Err(ParseError::new(
+                        line_number,
+                        1,
+                        "",
+                        "example code block is not closed",
+                    ))
+                }
+            }
+        }
+        example.input = input.join("\n");
+
+        examples.push(example);
+    }
+
+    Ok(examples)
+}
+
+/// Solves `example` with `S`, returning a description of every part that differs from the
+/// expected answer.
+pub fn check_example<S: Solution>(example: &Example) -> Result<Vec<String>, Error> {
+    let input = S::parse(&example.input)?;
+    let mut mismatches = vec![];
+
+    if let Some(expected) = &example.part_1 {
+        let actual = S::part1(&input)?.to_string();
+        if &actual != expected {
+            mismatches.push(format!("part 1: expected {expected}, got {actual}"));
+        }
+    }
+    if let Some(expected) = &example.part_2 {
+        let actual = S::part2(&input)?.to_string();
+        if &actual != expected {
+            mismatches.push(format!("part 2: expected {expected}, got {actual}"));
+        }
+    }
+
+    Ok(mismatches)
+}
+
+/// Test helper that checks every marked example of `readme` against `S`.
+///
+/// # Panics
+///
+/// When the readme has no marked examples or any example fails.
+pub fn assert_examples<S: Solution>(readme: &str) {
+    let examples = match parse_examples(readme) {
+        Ok(examples) => examples,
+        Err(error) => panic!("invalid readme: {error}"),
+    };
+    assert!(!examples.is_empty(), "readme has no marked examples");
+
+    let mut failures = vec![];
+    for example in &examples {
+        match check_example::<S>(example) {
+            Ok(mismatches) => failures.extend(
+                mismatches
+                    .into_iter()
+                    .map(|mismatch| format!("example at line {}, {mismatch}", example.line)),
+            ),
+            Err(error) => failures.push(format!("example at line {}: {error}", example.line)),
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::Summing;
+
+    const README: &str = "# Day 0
+
+For example:
+<!-- example part1: 6, part2: 3 -->
+```
+1
+2
+3
+```
+Only part 2 of this one:
+
+<!-- example part2: 1 -->
+```text
+1
+```
+";
+
+    #[test]
+    fn test_parse_examples() {
+        let examples = parse_examples(README).unwrap();
+
+        assert_eq!(
+            examples,
+            vec![
+                Example {
+                    line: 4,
+                    input: String::from("1\n2\n3"),
+                    part_1: Some(String::from("6")),
+                    part_2: Some(String::from("3")),
+                },
+                Example {
+                    line: 12,
+                    input: String::from("1"),
+                    part_1: None,
+                    part_2: Some(String::from("1")),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_malformed_markers() {
+        let error = parse_examples("<!-- example part3: 1 -->\n```\n1\n```").unwrap_err();
+        assert_eq!((error.line, error.text.as_str()), (1, "part3"));
+
+        let error = parse_examples("<!-- example part1: 1 -->\n\n```\n1\n```").unwrap_err();
+        assert_eq!(error.line, 2);
+
+        assert!(parse_examples("<!-- example part1: 1 -->\n```\n1\n").is_err());
+    }
+
+    #[test]
+    fn test_check_example() {
+        assert_examples::<Summing>(README);
+
+        let mut example = parse_examples(README).unwrap().remove(0);
+        example.part_2 = Some(String::from("4"));
+        assert_eq!(
+            check_example::<Summing>(&example).unwrap(),
+            vec![String::from("part 2: expected 4, got 3")]
+        );
+    }
+}
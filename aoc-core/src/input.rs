@@ -0,0 +1,101 @@
+//! Locating and reading puzzle inputs.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::Error;
+
+/// Path that stands for standard input.
+pub const STDIN: &str = "-";
+
+/// Reads a puzzle input file, or standard input for `-`.
+pub fn read_input(path: &Path) -> Result<String, Error> {
+    let result = if path == Path::new(STDIN) {
+        io::read_to_string(io::stdin())
+    } else {
+        fs::read_to_string(path)
+    };
+
+    result.map_err(|source| Error::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// Replaces every directory in `paths` by the `*.txt` files in it, sorted by name. Files and `-`
+/// are kept as they are. A day directory expands to its `input.txt` only, as its other files have
+/// other extensions.
+pub fn expand_inputs(paths: &[PathBuf]) -> Result<Vec<PathBuf>, Error> {
+    let mut inputs = vec![];
+
+    for path in paths {
+        if path.as_path() == Path::new(STDIN) || !path.is_dir() {
+            inputs.push(path.clone());
+            continue;
+        }
+
+        let io_error = |source| Error::Io {
+            path: path.clone(),
+            source,
+        };
+
+        let mut files = vec![];
+        for entry in fs::read_dir(path).map_err(io_error)? {
+            let file = entry.map_err(io_error)?.path();
+            if file.is_file() && file.extension().is_some_and(|ext| ext == "txt") {
+                files.push(file);
+            }
+        }
+
+        if files.is_empty() {
+            return Err(io_error(io::Error::new(
+                io::ErrorKind::NotFound,
+                "directory does not contain any *.txt files",
+            )));
+        }
+
+        files.sort();
+        inputs.extend(files);
+    }
+
+    Ok(inputs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_inputs() {
+        let dir = std::env::temp_dir().join(format!("aoc-core-expand-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        for name in ["b.txt", "a.txt", "notes.md"] {
+            fs::write(dir.join(name), "").unwrap();
+        }
+
+        let inputs = expand_inputs(&[PathBuf::from(STDIN), dir.clone(), dir.join("notes.md")]);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(
+            inputs.unwrap(),
+            vec![
+                PathBuf::from(STDIN),
+                dir.join("a.txt"),
+                dir.join("b.txt"),
+                dir.join("notes.md"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_expand_empty_directory() {
+        let dir = std::env::temp_dir().join(format!("aoc-core-empty-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let result = expand_inputs(std::slice::from_ref(&dir));
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(matches!(result, Err(Error::Io { .. })));
+    }
+}
@@ -0,0 +1,176 @@
+//! Shared building blocks for the Advent of Code 2024 solutions.
+
+use std::fmt::Display;
+pub mod alloc;
+pub mod cli;
+pub mod config;
+mod error;
+pub mod examples;
+pub mod input;
+pub mod num;
+pub mod report;
+pub mod timings;
+
+pub use error::{Error, ParseError};
+pub use input::read_input;
+use timings::{measure, Measurement, Timings};
+
+/// The shape every day of the calendar shares: parse the puzzle input once, then answer both
+/// parts from the parsed representation.
+pub trait Solution {
+    /// Day of December the puzzle was released on.
+    const DAY: u8;
+    /// Options of the day's own that it reads from its section in `aoc.toml`, besides `input`.
+    const OPTIONS: &'static [&'static str] = &[];
+
+    /// Parsed puzzle input, shared by both parts.
+    type Input;
+    /// Answer type of both parts.
+    type Answer: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, Error>;
+    fn part1(input: &Self::Input) -> Result<Self::Answer, Error>;
+    fn part2(input: &Self::Input) -> Result<Self::Answer, Error>;
+}
+
+/// Answers to both parts, already formatted for display, and what it took to find them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Answers {
+    pub day: u8,
+    pub part_1: String,
+    pub part_2: String,
+    pub timings: Timings,
+}
+
+/// Parses `input` and solves both parts of `S`.
+pub fn solve<S: Solution>(input: &str) -> Result<Answers, Error> {
+    let (parsed, parse) = measure(|| S::parse(input));
+    solve_parsed::<S>(&parsed?, parse)
+}
+
+/// Solves both parts of `S` for an input that is already parsed, which took `parse`.
+pub fn solve_parsed<S: Solution>(input: &S::Input, parse: Measurement) -> Result<Answers, Error> {
+    let (part_1_answer, part_1) = measure(|| S::part1(input));
+    let (part_2_answer, part_2) = measure(|| S::part2(input));
+
+    Ok(Answers {
+        day: S::DAY,
+        part_1: part_1_answer?.to_string(),
+        part_2: part_2_answer?.to_string(),
+        timings: Timings {
+            parse,
+            part_1,
+            part_2,
+        },
+    })
+}
+
+/// The answer to one part, formatted for display, and what it took to parse the input and find
+/// it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartAnswer {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub parse: Measurement,
+    pub solve: Measurement,
+}
+
+/// Parses `input` and solves only `part` of `S`.
+///
+/// # Panics
+///
+/// If `part` is not 1 or 2.
+pub fn solve_part<S: Solution>(input: &str, part: u8) -> Result<PartAnswer, Error> {
+    let solver = match part {
+        1 => S::part1,
+        2 => S::part2,
+        _ => panic!("Puzzles have parts 1 and 2, not {part}"),
+    };
+    let (parsed, parse) = measure(|| S::parse(input));
+    let parsed = parsed?;
+    let (answer, solve) = measure(|| solver(&parsed));
+
+    Ok(PartAnswer {
+        day: S::DAY,
+        part,
+        answer: answer?.to_string(),
+        parse,
+        solve,
+    })
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// Sums the numbers for part 1, takes the largest for part 2.
+    pub(crate) struct Summing;
+
+    impl Solution for Summing {
+        const DAY: u8 = 0;
+
+        type Input = Vec<usize>;
+        type Answer = usize;
+
+        fn parse(input: &str) -> Result<Self::Input, Error> {
+            input
+                .split_whitespace()
+                .map(|n| {
+                    n.parse()
+                        .map_err(|e| ParseError::at(1, input, n, format!("{e}")).into())
+                })
+                .collect()
+        }
+
+        fn part1(input: &Self::Input) -> Result<Self::Answer, Error> {
+            Ok(input.iter().sum())
+        }
+
+        fn part2(input: &Self::Input) -> Result<Self::Answer, Error> {
+            input
+                .iter()
+                .max()
+                .copied()
+                .ok_or(Error::Unsolvable(String::from("No numbers")))
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        let answers = solve::<Summing>("1 2 3").unwrap();
+        assert_eq!(answers.part_1, "6");
+        assert_eq!(answers.part_2, "3");
+    }
+
+    #[test]
+    fn test_solve_parsed() {
+        let answers = solve_parsed::<Summing>(&vec![1, 2, 3], Measurement::default()).unwrap();
+        assert_eq!(
+            (answers.part_1.as_str(), answers.part_2.as_str()),
+            ("6", "3")
+        );
+        assert_eq!(answers.timings.parse, Measurement::default());
+    }
+
+    #[test]
+    fn test_solve_propagates_errors() {
+        assert!(matches!(
+            solve::<Summing>("1 x"),
+            Err(Error::Parse(ParseError { column: 3, .. }))
+        ));
+        assert!(matches!(solve::<Summing>(""), Err(Error::Unsolvable(_))));
+    }
+
+    #[test]
+    fn test_solve_part() {
+        let answer = solve_part::<Summing>("1 2 3", 2).unwrap();
+        assert_eq!((answer.part, answer.answer.as_str()), (2, "3"));
+        // Part 1 has an answer without numbers, only part 2 fails.
+        assert_eq!(solve_part::<Summing>("", 1).unwrap().answer, "0");
+        assert!(matches!(
+            solve_part::<Summing>("", 2),
+            Err(Error::Unsolvable(_))
+        ));
+    }
+}
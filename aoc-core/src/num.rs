@@ -0,0 +1,62 @@
+//! The integer type of the answers, and arithmetic on it that reports overflow instead of
+//! wrapping around.
+//!
+//! Answers are 64 bits wide by default. The `wide` feature makes them 128 bits wide, for inputs
+//! whose answers do not fit in 64 bits:
+//!
+//! ```text
+//! cargo run -p aoc --features wide -- run 7
+//! ```
+
+use crate::Error;
+
+/// Integer that puzzle numbers are parsed into and answers are computed in.
+#[cfg(not(feature = "wide"))]
+pub type Int = u64;
+/// Integer that puzzle numbers are parsed into and answers are computed in.
+#[cfg(feature = "wide")]
+pub type Int = u128;
+
+/// Adds up `terms`, failing with [`Error::Overflow`] about `what` when the total does not fit in
+/// an [`Int`].
+pub fn checked_sum(terms: impl IntoIterator<Item = Int>, what: &str) -> Result<Int, Error> {
+    terms
+        .into_iter()
+        .try_fold(0, Int::checked_add)
+        .ok_or_else(|| Error::Overflow(what.to_owned()))
+}
+
+/// `a + b`, failing with [`Error::Overflow`] about `what` when it does not fit in an [`Int`].
+pub fn checked_add(a: Int, b: Int, what: &str) -> Result<Int, Error> {
+    a.checked_add(b)
+        .ok_or_else(|| Error::Overflow(what.to_owned()))
+}
+
+/// `a * b`, failing with [`Error::Overflow`] about `what` when it does not fit in an [`Int`].
+pub fn checked_mul(a: Int, b: Int, what: &str) -> Result<Int, Error> {
+    a.checked_mul(b)
+        .ok_or_else(|| Error::Overflow(what.to_owned()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checked_sum() {
+        assert_eq!(checked_sum([1, 2, 3], "the sum").unwrap(), 6);
+        assert_eq!(checked_sum([], "the sum").unwrap(), 0);
+        assert!(matches!(
+            checked_sum([Int::MAX, 1], "the sum"),
+            Err(Error::Overflow(what)) if what == "the sum"
+        ));
+    }
+
+    #[test]
+    fn test_checked_add_and_mul() {
+        assert_eq!(checked_add(6, 7, "the sum").unwrap(), 13);
+        assert!(checked_add(Int::MAX, 1, "the sum").is_err());
+        assert_eq!(checked_mul(6, 7, "the product").unwrap(), 42);
+        assert!(checked_mul(Int::MAX, 2, "the product").is_err());
+    }
+}
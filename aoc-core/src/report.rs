@@ -0,0 +1,189 @@
+//! Uniform output of answers, for humans (`text`) and for tooling (`json` and `csv`).
+//!
+//! Every answered part becomes one record with the fields `day`, `part`, `answer`,
+//! `parse_time_ns`, `solve_time_ns` and `input`. JSON output has one object per line, CSV output
+//! starts with a header row. Both parts share the parse time of the input.
+
+use std::io::{self, Write};
+use std::time::Duration;
+
+use clap::ValueEnum;
+use serde::Deserialize;
+
+use crate::Answers;
+
+#[derive(ValueEnum, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+const CSV_HEADER: &str = "day,part,answer,parse_time_ns,solve_time_ns,input";
+
+/// Writes answers in a [`Format`], taking care of headers.
+pub struct ReportWriter<W: Write> {
+    out: W,
+    format: Format,
+    header_written: bool,
+}
+
+impl<W: Write> ReportWriter<W> {
+    pub fn new(out: W, format: Format) -> Self {
+        ReportWriter {
+            out,
+            format,
+            header_written: false,
+        }
+    }
+
+    /// Writes both parts of `answers`, which were solved for the input named `input`.
+    pub fn write(&mut self, input: &str, answers: &Answers) -> io::Result<()> {
+        let parts = [
+            (1, &answers.part_1, answers.timings.part_1.time),
+            (2, &answers.part_2, answers.timings.part_2.time),
+        ];
+
+        for (part, answer, solve_time) in parts {
+            let day = answers.day;
+            let parse_time = answers.timings.parse.time;
+
+            match self.format {
+                Format::Text => writeln!(
+                    self.out,
+                    "Day {day} part {part}: {answer} (parsed in {parse_time:?}, solved in {solve_time:?}, {input})"
+                )?,
+                Format::Json => writeln!(
+                    self.out,
+                    "{{\"day\":{day},\"part\":{part},\"answer\":{},\"parse_time_ns\":{},\"solve_time_ns\":{},\"input\":{}}}",
+                    json_string(answer),
+                    nanos(parse_time),
+                    nanos(solve_time),
+                    json_string(input)
+                )?,
+                Format::Csv => {
+                    if !self.header_written {
+                        writeln!(self.out, "{CSV_HEADER}")?;
+                        self.header_written = true;
+                    }
+                    writeln!(
+                        self.out,
+                        "{day},{part},{},{},{},{}",
+                        csv_field(answer),
+                        nanos(parse_time),
+                        nanos(solve_time),
+                        csv_field(input)
+                    )?
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+}
+
+fn nanos(duration: Duration) -> u128 {
+    duration.as_nanos()
+}
+
+/// `value` as a quoted JSON string.
+pub fn json_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::timings::{Measurement, Timings};
+
+    fn measurement(nanos: u64) -> Measurement {
+        Measurement {
+            time: Duration::from_nanos(nanos),
+            peak_heap: None,
+        }
+    }
+
+    fn answers() -> Answers {
+        Answers {
+            day: 3,
+            part_1: String::from("161"),
+            part_2: String::from("48"),
+            timings: Timings {
+                parse: measurement(1500),
+                part_1: measurement(20),
+                part_2: measurement(30),
+            },
+        }
+    }
+
+    fn render(format: Format) -> String {
+        let mut writer = ReportWriter::new(vec![], format);
+        writer.write("day03/input.txt", &answers()).unwrap();
+        writer.write("-", &answers()).unwrap();
+        String::from_utf8(writer.into_inner()).unwrap()
+    }
+
+    #[test]
+    fn test_json() {
+        let output = render(Format::Json);
+        let mut lines = output.lines();
+        assert_eq!(
+            lines.next(),
+            Some(
+                r#"{"day":3,"part":1,"answer":"161","parse_time_ns":1500,"solve_time_ns":20,"input":"day03/input.txt"}"#
+            )
+        );
+        assert_eq!(
+            lines.next(),
+            Some(
+                r#"{"day":3,"part":2,"answer":"48","parse_time_ns":1500,"solve_time_ns":30,"input":"day03/input.txt"}"#
+            )
+        );
+        assert_eq!(lines.count(), 2);
+    }
+
+    #[test]
+    fn test_csv_writes_header_once() {
+        let output = render(Format::Csv);
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 5);
+        assert_eq!(lines[0], CSV_HEADER);
+        assert_eq!(lines[1], "3,1,161,1500,20,day03/input.txt");
+        assert_eq!(lines[3], "3,1,161,1500,20,-");
+    }
+
+    #[test]
+    fn test_escaping() {
+        assert_eq!(json_string("a\"b\\\u{1b}"), r#""a\"b\\\u001b""#);
+        assert_eq!(csv_field("1,2"), "\"1,2\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+}
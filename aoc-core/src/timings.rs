@@ -0,0 +1,118 @@
+//! Wall time and heap usage of the phases of a run.
+
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+use crate::alloc;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Measurement {
+    pub time: Duration,
+    /// Peak heap in use during the phase, `None` without the counting allocator.
+    pub peak_heap: Option<usize>,
+}
+
+/// Runs `f`, measuring how long it takes and how much heap it needs at most.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Measurement) {
+    alloc::reset_peak();
+    let start = Instant::now();
+    let result = f();
+    let time = start.elapsed();
+
+    (
+        result,
+        Measurement {
+            time,
+            peak_heap: alloc::peak(),
+        },
+    )
+}
+
+/// Measurements of parsing and solving both parts.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Timings {
+    pub parse: Measurement,
+    pub part_1: Measurement,
+    pub part_2: Measurement,
+}
+
+/// Writes one row per phase, followed by the total time and the overall peak.
+pub fn write_table(mut out: impl Write, phases: &[(&str, Measurement)]) -> io::Result<()> {
+    writeln!(
+        out,
+        "{:<10} {:>14} {:>12}",
+        "Phase", "Wall time", "Peak heap"
+    )?;
+
+    for (name, measurement) in phases {
+        writeln!(
+            out,
+            "{:<10} {:>14} {:>12}",
+            name,
+            format!("{:.2?}", measurement.time),
+            format_bytes(measurement.peak_heap)
+        )?;
+    }
+
+    let total: Duration = phases.iter().map(|(_, m)| m.time).sum();
+    let peak = phases.iter().filter_map(|(_, m)| m.peak_heap).max();
+    writeln!(
+        out,
+        "{:<10} {:>14} {:>12}",
+        "total",
+        format!("{total:.2?}"),
+        format_bytes(peak)
+    )
+}
+
+pub fn format_bytes(bytes: Option<usize>) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+    let Some(bytes) = bytes else {
+        return String::from("-");
+    };
+
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{size:.1} {}", UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(None), "-");
+        assert_eq!(format_bytes(Some(512)), "512 B");
+        assert_eq!(format_bytes(Some(1536)), "1.5 KiB");
+        assert_eq!(format_bytes(Some(3 * 1024 * 1024)), "3.0 MiB");
+    }
+
+    #[test]
+    fn test_table() {
+        let phase = Measurement {
+            time: Duration::from_millis(2),
+            peak_heap: Some(2048),
+        };
+        let mut out = vec![];
+        write_table(&mut out, &[("parse", phase), ("part 1", phase)]).unwrap();
+
+        let table = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[1].starts_with("parse"));
+        assert!(lines[1].ends_with("2.00ms      2.0 KiB"));
+        assert!(lines[3].starts_with("total"));
+        assert!(lines[3].contains("4.00ms"));
+    }
+}
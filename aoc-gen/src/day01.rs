@@ -0,0 +1,37 @@
+use rand::Rng as _;
+
+use crate::Rng;
+
+/// Two columns of five digit location IDs. About a third of the right column repeats IDs from
+/// the left one, so part 2 has similarities to find.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let left: Vec<u32> = (0..size).map(|_| rng.random_range(10000..100000)).collect();
+
+    let mut input = String::new();
+    for &id in &left {
+        let right = if rng.random_bool(1.0 / 3.0) {
+            left[rng.random_range(0..left.len())]
+        } else {
+            rng.random_range(10000..100000)
+        };
+        input.push_str(&format!("{id}   {right}\n"));
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_format() {
+        let input = generate(&mut Rng::seed_from_u64(1), 50);
+
+        assert_eq!(input.lines().count(), 50);
+        assert!(input.lines().all(|line| {
+            let ids: Vec<&str> = line.split("   ").collect();
+            ids.len() == 2 && ids.iter().all(|id| id.len() == 5)
+        }));
+    }
+}
@@ -0,0 +1,49 @@
+use rand::Rng as _;
+
+use crate::Rng;
+
+/// Reports of 5 to 8 levels. Most are steadily increasing or decreasing by 1 to 3, some get one
+/// or two bad levels, so every kind of (un)safe report shows up.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+
+    for _ in 0..size {
+        let len = rng.random_range(5..=8);
+        let increasing = rng.random_bool(0.5);
+        let mut level: i64 = rng.random_range(10..90);
+        let mut levels = vec![level];
+
+        for _ in 1..len {
+            let delta = rng.random_range(1..=3);
+            level += if increasing { delta } else { -delta };
+            levels.push(level);
+        }
+
+        for _ in 0..rng.random_range(0..=2) {
+            let index = rng.random_range(0..len);
+            levels[index] += rng.random_range(-4..=4);
+        }
+
+        let levels: Vec<String> = levels.iter().map(|&l| l.max(1).to_string()).collect();
+        input.push_str(&levels.join(" "));
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_format() {
+        let input = generate(&mut Rng::seed_from_u64(1), 100);
+
+        assert_eq!(input.lines().count(), 100);
+        for line in input.lines() {
+            let levels: Vec<u64> = line.split(' ').map(|l| l.parse().unwrap()).collect();
+            assert!((5..=8).contains(&levels.len()));
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use rand::seq::IndexedRandom;
+use rand::Rng as _;
+
+use crate::Rng;
+
+const NOISE: &[&str] = &[
+    "from()", "what()", "select()", "who()", "where()", "how()", "#", "$", "%", "&", "*", "@", "!",
+    "^", "[", "]", "<", ">", "{", "}", "'", " ", "+", "-", ",", "?", ";", ":", "/", "(", ")",
+    "mul", "do", "don't",
+];
+
+/// Corrupted memory with `size` valid `mul(x,y)` instructions. They are mixed with noise, broken
+/// instructions such as `mul(4*` or `mul ( 2 , 4 )`, and `do()`/`don't()` toggles, spread over
+/// lines of about 3000 characters.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    let mut line_len = 0;
+
+    for _ in 0..size {
+        let start = input.len();
+
+        for _ in 0..rng.random_range(0..6) {
+            input.push_str(NOISE.choose(rng).unwrap_or(&""));
+        }
+
+        match rng.random_range(0..10) {
+            0 => input.push_str("do()"),
+            1 => input.push_str("don't()"),
+            2 => input.push_str(&broken_mul(rng)),
+            _ => {}
+        }
+
+        let (x, y) = (rng.random_range(1..1000), rng.random_range(1..1000));
+        input.push_str(&format!("mul({x},{y})"));
+
+        line_len += input.len() - start;
+        if line_len > 3000 {
+            input.push('\n');
+            line_len = 0;
+        }
+    }
+
+    if !input.ends_with('\n') {
+        input.push('\n');
+    }
+    input
+}
+
+fn broken_mul(rng: &mut Rng) -> String {
+    let (x, y) = (rng.random_range(1..1000), rng.random_range(1..1000));
+
+    match rng.random_range(0..5) {
+        0 => format!("mul({x}*"),
+        1 => format!("mul({x},{y}!"),
+        2 => format!("?({x},{y})"),
+        3 => format!("mul ( {x} , {y} )"),
+        _ => format!("mul({x}{y},{y})"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_contains_instructions() {
+        let input = generate(&mut Rng::seed_from_u64(1), 500);
+
+        assert!(input.matches("mul(").count() >= 500);
+        assert!(input.contains("don't()"));
+        assert!(input.lines().count() > 1);
+    }
+}
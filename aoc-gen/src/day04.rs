@@ -0,0 +1,88 @@
+use rand::seq::IndexedRandom;
+use rand::Rng as _;
+
+use crate::Rng;
+
+const LETTERS: [u8; 4] = *b"XMAS";
+/// Row and column steps of the eight directions a word can be written in.
+const DIRECTIONS: [(isize, isize); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+/// A `size` by `size` grid of the letters `XMAS`, with extra `XMAS` words and `MAS` crosses
+/// planted in random places and directions.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut grid: Vec<Vec<u8>> = (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| *LETTERS.choose(rng).unwrap_or(&b'X'))
+                .collect()
+        })
+        .collect();
+
+    for _ in 0..size * size / 20 {
+        let row = rng.random_range(0..size) as isize;
+        let col = rng.random_range(0..size) as isize;
+
+        if rng.random_bool(0.5) {
+            let (dr, dc) = *DIRECTIONS.choose(rng).unwrap_or(&(0, 1));
+            plant(&mut grid, b"XMAS", (row, col), (dr, dc));
+        } else {
+            // The centre of a cross, with each diagonal read in a random direction.
+            let (a, b) = (rng.random_bool(0.5), rng.random_bool(0.5));
+            let diagonal = |forward: bool| if forward { b"MAS" } else { b"SAM" };
+            plant(&mut grid, diagonal(a), (row - 1, col - 1), (1, 1));
+            plant(&mut grid, diagonal(b), (row - 1, col + 1), (1, -1));
+        }
+    }
+
+    let mut input = String::new();
+    for row in grid {
+        input.extend(row.into_iter().map(char::from));
+        input.push('\n');
+    }
+    input
+}
+
+/// Writes `word` from `start` in direction `step`, unless it would leave the grid.
+fn plant(grid: &mut [Vec<u8>], word: &[u8], start: (isize, isize), step: (isize, isize)) {
+    let size = grid.len() as isize;
+    let coords: Vec<(isize, isize)> = (0..word.len() as isize)
+        .map(|i| (start.0 + i * step.0, start.1 + i * step.1))
+        .collect();
+
+    if coords
+        .iter()
+        .all(|&(row, col)| (0..size).contains(&row) && (0..size).contains(&col))
+    {
+        for (&(row, col), &letter) in coords.iter().zip(word) {
+            grid[row as usize][col as usize] = letter;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_square_grid_of_letters() {
+        let input = generate(&mut Rng::seed_from_u64(1), 30);
+
+        assert_eq!(input.lines().count(), 30);
+        assert!(input.lines().all(|line| line.len() == 30));
+        assert!(input
+            .lines()
+            .flat_map(str::bytes)
+            .all(|b| LETTERS.contains(&b)));
+    }
+}
@@ -0,0 +1,68 @@
+use rand::seq::SliceRandom;
+use rand::Rng as _;
+
+use crate::Rng;
+
+/// Number of distinct page numbers, as in the real input.
+const PAGES: usize = 49;
+
+/// Ordering rules and `size` updates. The pages follow a hidden total order and there is a rule
+/// for every pair of pages, so each update has exactly one correct order. Updates have an odd
+/// number of distinct pages and about half of them are already in order.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut order: Vec<u32> = (10..100).collect();
+    order.shuffle(rng);
+    order.truncate(PAGES);
+
+    let mut rules = vec![];
+    for (i, left) in order.iter().enumerate() {
+        for right in &order[i + 1..] {
+            rules.push(format!("{left}|{right}\n"));
+        }
+    }
+    rules.shuffle(rng);
+
+    let mut input = rules.concat();
+    input.push('\n');
+
+    for _ in 0..size {
+        let len = rng.random_range(2..=11) * 2 + 1;
+        let mut positions: Vec<usize> = (0..order.len()).collect();
+        positions.shuffle(rng);
+        positions.truncate(len);
+
+        if rng.random_bool(0.5) {
+            positions.sort();
+        }
+
+        let pages: Vec<String> = positions.iter().map(|&p| order[p].to_string()).collect();
+        input.push_str(&pages.join(","));
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_sections() {
+        let input = generate(&mut Rng::seed_from_u64(1), 20);
+        let (rules, updates) = input.split_once("\n\n").unwrap();
+
+        assert_eq!(rules.lines().count(), PAGES * (PAGES - 1) / 2);
+        assert_eq!(updates.lines().count(), 20);
+        for update in updates.lines() {
+            let pages: Vec<&str> = update.split(',').collect();
+            assert_eq!(pages.len() % 2, 1);
+            for pair in pages.windows(2) {
+                assert!(
+                    rules.contains(&format!("{}|{}", pair[0], pair[1]))
+                        || rules.contains(&format!("{}|{}", pair[1], pair[0]))
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use std::collections::HashSet;
+
+use rand::Rng as _;
+
+use crate::Rng;
+
+/// A `size` by `size` lab map with about 5% obstructions and a single guard facing up. Maps
+/// where the guard would walk in circles are thrown away, so the guard always leaves the map.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+
+    loop {
+        let mut map: Vec<Vec<u8>> = (0..size)
+            .map(|_| {
+                (0..size)
+                    .map(|_| if rng.random_bool(0.05) { b'#' } else { b'.' })
+                    .collect()
+            })
+            .collect();
+
+        let guard = (rng.random_range(0..size), rng.random_range(0..size));
+        map[guard.0][guard.1] = b'^';
+
+        if leaves_map(&map, guard) {
+            let mut input = String::new();
+            for row in map {
+                input.extend(row.into_iter().map(char::from));
+                input.push('\n');
+            }
+            return input;
+        }
+    }
+}
+
+/// Walks the guard from `start` and tells whether they step off the map.
+fn leaves_map(map: &[Vec<u8>], start: (usize, usize)) -> bool {
+    let (mut row, mut col) = start;
+    let (mut dr, mut dc) = (-1isize, 0isize);
+    let mut seen = HashSet::new();
+
+    while seen.insert((row, col, dr, dc)) {
+        let next = (
+            row.checked_add_signed(dr).filter(|&r| r < map.len()),
+            col.checked_add_signed(dc).filter(|&c| c < map.len()),
+        );
+        let (Some(next_row), Some(next_col)) = next else {
+            return true;
+        };
+
+        if map[next_row][next_col] == b'#' {
+            (dr, dc) = (dc, -dr);
+        } else {
+            (row, col) = (next_row, next_col);
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_single_guard() {
+        let input = generate(&mut Rng::seed_from_u64(1), 40);
+
+        assert_eq!(input.lines().count(), 40);
+        assert!(input.lines().all(|line| line.len() == 40));
+        assert_eq!(input.matches('^').count(), 1);
+    }
+
+    #[test]
+    fn test_leaves_map() {
+        let map = |text: &str| -> Vec<Vec<u8>> { text.lines().map(|l| l.into()).collect() };
+
+        assert!(leaves_map(&map("...\n.^.\n..."), (1, 1)));
+        assert!(!leaves_map(&map(".#..\n...#\n#^..\n..#."), (2, 1)));
+    }
+}
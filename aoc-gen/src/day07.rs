@@ -0,0 +1,72 @@
+use rand::Rng as _;
+
+use crate::Rng;
+
+/// `size` calibration equations of 2 to 12 numbers below 1000. A third of the equations get
+/// their test value by evaluating random `+`, `*` and `||` operators, so they are solvable by
+/// construction. Another third get a nearby value that usually is not, and the last third the
+/// value of the numbers without the first one, which only a solver that drops it can reach.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+
+    for index in 0..size {
+        let len = rng.random_range(2..=12);
+        let numbers: Vec<u64> = (0..len).map(|_| rng.random_range(1..1000)).collect();
+        let evaluated = if index % 3 == 2 {
+            &numbers[1..]
+        } else {
+            &numbers[..]
+        };
+
+        // Anything that fits in 48 bits stays in range for every solver, even with an offset.
+        let value = loop {
+            match evaluate(rng, evaluated) {
+                Some(value) if value < 1 << 48 => break value,
+                _ => continue,
+            }
+        };
+        let value = if index % 3 == 1 {
+            value + rng.random_range(1..100)
+        } else {
+            value
+        };
+
+        let numbers: Vec<String> = numbers.iter().map(u64::to_string).collect();
+        input.push_str(&format!("{value}: {}\n", numbers.join(" ")));
+    }
+    input
+}
+
+/// Evaluates `numbers` left to right with random operators, `None` on overflow.
+fn evaluate(rng: &mut Rng, numbers: &[u64]) -> Option<u64> {
+    let mut value = numbers[0];
+
+    for &number in &numbers[1..] {
+        value = match rng.random_range(0..3) {
+            0 => value.checked_add(number)?,
+            1 => value.checked_mul(number)?,
+            _ => value
+                .checked_mul(10u64.pow(number.ilog10() + 1))?
+                .checked_add(number)?,
+        };
+    }
+    Some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_format() {
+        let input = generate(&mut Rng::seed_from_u64(1), 30);
+
+        assert_eq!(input.lines().count(), 30);
+        for line in input.lines() {
+            let (value, numbers) = line.split_once(": ").unwrap();
+            assert!(value.parse::<u64>().unwrap() < 1 << 49);
+            assert!(numbers.split(' ').all(|n| n.parse::<u64>().is_ok()));
+        }
+    }
+}
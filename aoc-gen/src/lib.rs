@@ -0,0 +1,123 @@
+//! Random puzzle inputs, for stress testing the solvers on inputs larger and weirder than the
+//! checked-in `input.txt` files.
+//!
+//! Every generator writes the exact format the day's parser accepts and is deterministic for a
+//! given size and seed. The inputs keep the properties the puzzles promise, for example every
+//! page pair of a day 5 update is covered by a rule and the day 6 guard always leaves the map.
+//!
+//! ```
+//! let input = aoc_gen::generate(1, 3, 42).unwrap();
+//!
+//! assert_eq!(input.lines().count(), 3);
+//! assert_eq!(aoc_gen::generate(1, 3, 42), Some(input));
+//! ```
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+mod day01;
+mod day02;
+mod day03;
+mod day04;
+mod day05;
+mod day06;
+mod day07;
+
+/// The random number generator all generators draw from.
+pub type Rng = ChaCha8Rng;
+
+/// Input generator for one day.
+pub struct Generator {
+    pub day: u8,
+    /// What `size` counts.
+    pub size_unit: &'static str,
+    /// Size of the real puzzle input.
+    pub default_size: usize,
+    pub generate: fn(&mut Rng, usize) -> String,
+}
+
+pub const GENERATORS: &[Generator] = &[
+    Generator {
+        day: 1,
+        size_unit: "location ID pairs",
+        default_size: 1000,
+        generate: day01::generate,
+    },
+    Generator {
+        day: 2,
+        size_unit: "reports",
+        default_size: 1000,
+        generate: day02::generate,
+    },
+    Generator {
+        day: 3,
+        size_unit: "mul instructions",
+        default_size: 700,
+        generate: day03::generate,
+    },
+    Generator {
+        day: 4,
+        size_unit: "rows and columns",
+        default_size: 140,
+        generate: day04::generate,
+    },
+    Generator {
+        day: 5,
+        size_unit: "updates",
+        default_size: 200,
+        generate: day05::generate,
+    },
+    Generator {
+        day: 6,
+        size_unit: "rows and columns",
+        default_size: 130,
+        generate: day06::generate,
+    },
+    Generator {
+        day: 7,
+        size_unit: "equations",
+        default_size: 850,
+        generate: day07::generate,
+    },
+];
+
+pub fn find(day: u8) -> Option<&'static Generator> {
+    GENERATORS.iter().find(|generator| generator.day == day)
+}
+
+/// Input for `day` of the given size, `None` when there is no generator for that day.
+pub fn generate(day: u8, size: usize, seed: u64) -> Option<String> {
+    let generator = find(day)?;
+    let mut rng = Rng::seed_from_u64(seed);
+
+    Some((generator.generate)(&mut rng, size))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deterministic() {
+        for generator in GENERATORS {
+            let first = generate(generator.day, 20, 7);
+            assert_eq!(
+                first,
+                generate(generator.day, 20, 7),
+                "day {}",
+                generator.day
+            );
+            assert_ne!(
+                first,
+                generate(generator.day, 20, 8),
+                "day {}",
+                generator.day
+            );
+        }
+    }
+
+    #[test]
+    fn test_unknown_day() {
+        assert_eq!(generate(25, 10, 0), None);
+    }
+}
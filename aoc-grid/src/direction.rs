@@ -0,0 +1,71 @@
+/// One of the eight directions on a grid.
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    /// The four orthogonal directions, clockwise from [`Direction::Up`].
+    pub const ORTHOGONAL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// All eight directions, clockwise from [`Direction::Up`].
+    pub const ALL: [Direction; 8] = [
+        Direction::Up,
+        Direction::UpRight,
+        Direction::Right,
+        Direction::DownRight,
+        Direction::Down,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+    ];
+
+    /// Change of `(row, col)` for one step in this direction.
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (-1, 0),
+            Direction::UpRight => (-1, 1),
+            Direction::Right => (0, 1),
+            Direction::DownRight => (1, 1),
+            Direction::Down => (1, 0),
+            Direction::DownLeft => (1, -1),
+            Direction::Left => (0, -1),
+            Direction::UpLeft => (-1, -1),
+        }
+    }
+
+    /// The direction a quarter turn clockwise.
+    pub fn rotate_right(self) -> Direction {
+        let index = Direction::ALL.iter().position(|&d| d == self).unwrap_or(0);
+        Direction::ALL[(index + 2) % Direction::ALL.len()]
+    }
+
+    pub fn opposite(self) -> Direction {
+        self.rotate_right().rotate_right()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rotation() {
+        assert_eq!(Direction::Up.rotate_right(), Direction::Right);
+        assert_eq!(Direction::Left.rotate_right(), Direction::Up);
+        assert_eq!(Direction::UpLeft.rotate_right(), Direction::UpRight);
+        assert_eq!(Direction::DownRight.opposite(), Direction::UpLeft);
+    }
+}
@@ -0,0 +1,328 @@
+//! A rectangular grid with checked coordinate access, for the days that walk or search a map.
+//!
+//! ```
+//! use aoc_grid::{Coord, Direction, Grid};
+//!
+//! let grid = Grid::parse("XMAS\n....", |_, c| Ok(c)).unwrap();
+//! let word: String = grid
+//!     .ray(Coord::new(0, 0), Direction::Right)
+//!     .map(|(_, &c)| c)
+//!     .collect();
+//!
+//! assert_eq!(word, "XMAS");
+//! assert_eq!(grid.get(Coord::new(2, 0)), None);
+//! ```
+
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+use aoc_core::ParseError;
+use aoc_parse::char_grid;
+
+mod direction;
+
+pub use direction::Direction;
+
+/// Position on a grid, `(0, 0)` is the top left corner.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash, Debug)]
+pub struct Coord {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Coord {
+    pub fn new(row: usize, col: usize) -> Coord {
+        Coord { row, col }
+    }
+
+    /// The neighbouring coordinate in `direction`, `None` when that would be negative.
+    pub fn step(self, direction: Direction) -> Option<Coord> {
+        let (row, col) = direction.offset();
+
+        Some(Coord {
+            row: self.row.checked_add_signed(row)?,
+            col: self.col.checked_add_signed(col)?,
+        })
+    }
+}
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Grid of `width` columns from cells stored row by row.
+    ///
+    /// # Panics
+    ///
+    /// When the number of cells is not a multiple of `width`.
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Grid<T> {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width),
+            "{} cells do not fill rows of width {width}",
+            cells.len()
+        );
+
+        Grid {
+            height: cells.len() / width,
+            cells,
+            width,
+        }
+    }
+
+    /// Parses a rectangular block of text, mapping every character with `cell(coord, c)`. An
+    /// `Err` from `cell` is the reason for a [`ParseError`] at that character.
+    pub fn parse(
+        input: &str,
+        mut cell: impl FnMut(Coord, char) -> Result<T, String>,
+    ) -> Result<Grid<T>, ParseError> {
+        let grid = char_grid(input, |row, col, c| cell(Coord { row, col }, c))?;
+
+        Ok(Grid {
+            cells: grid.cells,
+            width: grid.width,
+            height: grid.height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, coord: Coord) -> bool {
+        coord.row < self.height && coord.col < self.width
+    }
+
+    /// The cell at `coord`, `None` outside the grid.
+    pub fn get(&self, coord: Coord) -> Option<&T> {
+        self.offset(coord).map(|offset| &self.cells[offset])
+    }
+
+    /// The cell at `coord`, `None` outside the grid.
+    pub fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        self.offset(coord).map(|offset| &mut self.cells[offset])
+    }
+
+    /// The neighbouring coordinate in `direction`, `None` when that is outside the grid.
+    pub fn step(&self, coord: Coord, direction: Direction) -> Option<Coord> {
+        coord.step(direction).filter(|&next| self.contains(next))
+    }
+
+    /// Cells next to `coord` in the four orthogonal directions.
+    pub fn neighbours(&self, coord: Coord) -> impl Iterator<Item = (Coord, &T)> {
+        self.neighbours_in(coord, &Direction::ORTHOGONAL)
+    }
+
+    /// Cells next to `coord` in all eight directions, diagonals included.
+    pub fn neighbours8(&self, coord: Coord) -> impl Iterator<Item = (Coord, &T)> {
+        self.neighbours_in(coord, &Direction::ALL)
+    }
+
+    fn neighbours_in<'a>(
+        &'a self,
+        coord: Coord,
+        directions: &'static [Direction],
+    ) -> impl Iterator<Item = (Coord, &'a T)> {
+        directions
+            .iter()
+            .filter_map(move |&direction| self.step(coord, direction))
+            .map(|next| (next, &self[next]))
+    }
+
+    /// Cells from `start` (included) in `direction` up to the edge of the grid. Empty when
+    /// `start` is outside the grid.
+    pub fn ray(&self, start: Coord, direction: Direction) -> impl Iterator<Item = (Coord, &T)> {
+        let start = Some(start).filter(|&start| self.contains(start));
+
+        std::iter::successors(start, move |&coord| self.step(coord, direction))
+            .map(|coord| (coord, &self[coord]))
+    }
+
+    /// Cells of row `row`, from left to right.
+    ///
+    /// # Panics
+    ///
+    /// When `row` is outside the grid.
+    pub fn row(&self, row: usize) -> &[T] {
+        assert!(row < self.height, "row {row} is outside the grid");
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    /// All rows, from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    /// Cells of column `col`, from top to bottom. Empty when `col` is outside the grid.
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        self.ray(Coord { row: 0, col }, Direction::Down)
+            .map(|(_, cell)| cell)
+    }
+
+    /// Cells on the `\` diagonal from `start` down to the edge of the grid.
+    pub fn diagonal(&self, start: Coord) -> impl Iterator<Item = &T> {
+        self.ray(start, Direction::DownRight).map(|(_, cell)| cell)
+    }
+
+    /// Cells on the `/` diagonal from `start` down to the edge of the grid.
+    pub fn anti_diagonal(&self, start: Coord) -> impl Iterator<Item = &T> {
+        self.ray(start, Direction::DownLeft).map(|(_, cell)| cell)
+    }
+
+    /// Every cell with its coordinate, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        let width = self.width;
+
+        self.cells.iter().enumerate().map(move |(offset, cell)| {
+            let coord = Coord {
+                row: offset / width,
+                col: offset % width,
+            };
+            (coord, cell)
+        })
+    }
+
+    /// Coordinates of every cell, row by row.
+    pub fn coords(&self) -> impl Iterator<Item = Coord> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |offset| Coord {
+            row: offset / width,
+            col: offset % width,
+        })
+    }
+
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    fn offset(&self, coord: Coord) -> Option<usize> {
+        self.contains(coord)
+            .then(|| coord.row * self.width + coord.col)
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    /// # Panics
+    ///
+    /// When `coord` is outside the grid.
+    fn index(&self, coord: Coord) -> &T {
+        match self.get(coord) {
+            Some(cell) => cell,
+            None => panic!(
+                "{coord:?} is outside the {}x{} grid",
+                self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, coord: Coord) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(coord) {
+            Some(cell) => cell,
+            None => panic!("{coord:?} is outside the {width}x{height} grid"),
+        }
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Grid<char> {
+        Grid::parse("abc\ndef\nghi", |_, c| Ok(c)).unwrap()
+    }
+
+    fn collect<'a>(cells: impl Iterator<Item = &'a char>) -> String {
+        cells.collect()
+    }
+
+    #[test]
+    fn test_checked_access() {
+        let mut grid = example();
+
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid.get(Coord::new(1, 2)), Some(&'f'));
+        assert_eq!(grid.get(Coord::new(3, 0)), None);
+        assert_eq!(grid.get(Coord::new(0, 3)), None);
+        assert_eq!(grid.step(Coord::new(0, 0), Direction::Up), None);
+        assert_eq!(
+            grid.step(Coord::new(0, 0), Direction::DownRight),
+            Some(Coord::new(1, 1))
+        );
+
+        grid[Coord::new(0, 0)] = 'x';
+        assert_eq!(grid.row(0), ['x', 'b', 'c']);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = example();
+
+        let corner: String = grid.neighbours(Coord::new(0, 0)).map(|(_, &c)| c).collect();
+        assert_eq!(corner, "bd");
+
+        let centre: String = grid
+            .neighbours8(Coord::new(1, 1))
+            .map(|(_, &c)| c)
+            .collect();
+        assert_eq!(centre, "bcfihgda");
+    }
+
+    #[test]
+    fn test_views() {
+        let grid = example();
+
+        assert_eq!(collect(grid.column(1)), "beh");
+        assert_eq!(collect(grid.column(3)), "");
+        assert_eq!(collect(grid.diagonal(Coord::new(0, 0))), "aei");
+        assert_eq!(collect(grid.anti_diagonal(Coord::new(0, 2))), "ceg");
+        assert_eq!(
+            collect(grid.ray(Coord::new(2, 2), Direction::Left).map(|(_, c)| c)),
+            "ihg"
+        );
+        assert_eq!(grid.rows().count(), 3);
+        assert_eq!(grid.iter().nth(5), Some((Coord::new(1, 2), &'f')));
+    }
+
+    #[test]
+    fn test_parse_error_location() {
+        let error = Grid::parse("ab\nc!", |_, c| match c {
+            'a'..='z' => Ok(c),
+            _ => Err(String::from("expected a letter")),
+        })
+        .err()
+        .unwrap();
+
+        assert_eq!(error, ParseError::new(2, 2, "!", "expected a letter"));
+    }
+
+    #[test]
+    fn test_display() {
+        let grid = Grid::from_cells(2, vec![1, 2, 3, 4]);
+        assert_eq!(grid.to_string(), "12\n34\n");
+    }
+}
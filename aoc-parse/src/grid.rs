@@ -0,0 +1,85 @@
+use aoc_core::ParseError;
+
+/// The cells of a rectangular character grid, row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CharGrid<T> {
+    pub cells: Vec<T>,
+    pub width: usize,
+    pub height: usize,
+}
+
+/// Parses a rectangular grid of characters, mapping every character with `cell(row, col, c)`.
+/// Rows and columns start at 0. An `Err` from `cell` is the reason for a [`ParseError`] at that
+/// character.
+pub fn char_grid<T>(
+    input: &str,
+    mut cell: impl FnMut(usize, usize, char) -> Result<T, String>,
+) -> Result<CharGrid<T>, ParseError> {
+    let width = match input.lines().next() {
+        Some(first_line) if !first_line.is_empty() => first_line.chars().count(),
+        _ => return Err(ParseError::new(1, 1, "", "grid is empty")),
+    };
+
+    let mut cells = Vec::with_capacity(input.len());
+    let mut height = 0;
+
+    for (row, line) in input.lines().enumerate() {
+        if line.chars().count() != width {
+            return Err(ParseError::at(
+                row + 1,
+                line,
+                line,
+                format!("expected a line of width {width}"),
+            ));
+        }
+
+        for (col, (offset, c)) in line.char_indices().enumerate() {
+            let value = cell(row, col, c).map_err(|reason| {
+                ParseError::at(row + 1, line, &line[offset..offset + c.len_utf8()], reason)
+            })?;
+            cells.push(value);
+        }
+
+        height += 1;
+    }
+
+    Ok(CharGrid {
+        cells,
+        width,
+        height,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wall(_: usize, _: usize, c: char) -> Result<bool, String> {
+        match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err(String::from("expected `#` or `.`")),
+        }
+    }
+
+    #[test]
+    fn test_char_grid() {
+        let grid = char_grid("#..\n.#.\n", wall).unwrap();
+
+        assert_eq!((grid.width, grid.height), (3, 2));
+        assert_eq!(grid.cells, vec![true, false, false, false, true, false]);
+    }
+
+    #[test]
+    fn test_char_grid_errors() {
+        assert_eq!(
+            char_grid("#..\n.x.", wall),
+            Err(ParseError::new(2, 2, "x", "expected `#` or `.`"))
+        );
+        assert_eq!(
+            char_grid("#..\n.#", wall).err().map(|e| (e.line, e.column)),
+            Some((2, 1))
+        );
+        assert!(char_grid("", wall).is_err());
+    }
+}
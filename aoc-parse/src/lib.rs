@@ -0,0 +1,118 @@
+//! Parsers for the shapes puzzle inputs keep coming in: number lists, `key: values` lines,
+//! `a|b` pairs, blank-line separated sections and character grids.
+//!
+//! The combinators are plain [`nom`] parsers for a single line. [`lines`] and [`Section::lines`]
+//! run them over every line of an input and turn failures into a [`ParseError`] pointing at the
+//! offending text.
+//!
+//! ```
+//! use aoc_parse::{key_values, lines, number, number_list};
+//! use nom::character::complete::space1;
+//!
+//! let equations = lines(
+//!     "190: 10 19\n83: 17 5",
+//!     key_values(number::<usize>, number_list(space1)),
+//!     "expected an equation",
+//! )
+//! .unwrap();
+//!
+//! assert_eq!(equations, vec![(190, vec![10, 19]), (83, vec![17, 5])]);
+//! ```
+//!
+//! [`ParseError`]: aoc_core::ParseError
+
+use std::str::FromStr;
+
+use nom::character::complete::{char, digit1, space0};
+use nom::combinator::map_res;
+use nom::multi::separated_list1;
+use nom::sequence::{pair, separated_pair};
+use nom::{IResult, Parser};
+
+mod grid;
+mod lines;
+
+pub use grid::{char_grid, CharGrid};
+pub use lines::{lines, parse_line, sections, Section};
+
+/// An unsigned number in decimal digits. Fails on the digits when they do not fit in `T`.
+pub fn number<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(digit1, str::parse)(input)
+}
+
+/// One or more numbers separated by `separator`, e.g. `75,47,61` or `7 6 4 2 1`.
+pub fn number_list<'a, T, O>(
+    separator: impl Parser<&'a str, O, nom::error::Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<T>>
+where
+    T: FromStr,
+{
+    separated_list1(separator, number)
+}
+
+/// Two numbers separated by `separator`, e.g. `47|53` or `3   4`.
+pub fn number_pair<'a, T, O>(
+    separator: impl Parser<&'a str, O, nom::error::Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (T, T)>
+where
+    T: FromStr,
+{
+    separated_pair(number, separator, number)
+}
+
+/// A `key: values` line, with any number of spaces after the colon.
+pub fn key_values<'a, K, V>(
+    key: impl Parser<&'a str, K, nom::error::Error<&'a str>>,
+    values: impl Parser<&'a str, V, nom::error::Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (K, V)> {
+    separated_pair(key, pair(char(':'), space0), values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::bytes::complete::tag;
+    use nom::character::complete::space1;
+
+    #[test]
+    fn test_number() {
+        assert_eq!(number::<usize>("42 rest"), Ok((" rest", 42)));
+        assert!(number::<usize>("x42").is_err());
+
+        let too_big = "99999999999999999999999";
+        match number::<u64>(too_big) {
+            Err(nom::Err::Error(error)) => assert_eq!(error.input, too_big),
+            other => panic!("expected an error, got {other:?}"),
+        }
+        assert!(number::<u128>(too_big).is_ok());
+    }
+
+    #[test]
+    fn test_number_list() {
+        assert_eq!(
+            number_list::<usize, _>(tag(","))("75,47,61"),
+            Ok(("", vec![75, 47, 61]))
+        );
+        assert_eq!(
+            number_list::<usize, _>(space1)("7  6 4 -2"),
+            Ok((" -2", vec![7, 6, 4]))
+        );
+    }
+
+    #[test]
+    fn test_number_pair() {
+        assert_eq!(
+            number_pair::<usize, _>(tag("|"))("47|53"),
+            Ok(("", (47, 53)))
+        );
+        assert_eq!(number_pair::<usize, _>(space1)("3   4"), Ok(("", (3, 4))));
+    }
+
+    #[test]
+    fn test_key_values() {
+        let mut parser = key_values(number::<usize>, number_list::<usize, _>(space1));
+        assert_eq!(parser("190: 10 19"), Ok(("", (190, vec![10, 19]))));
+        assert_eq!(parser("190:10"), Ok(("", (190, vec![10]))));
+        assert!(parser("190 10").is_err());
+    }
+}
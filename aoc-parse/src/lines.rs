@@ -0,0 +1,139 @@
+use aoc_core::ParseError;
+use nom::IResult;
+
+/// Runs `parser` on `line`, which has to consume all of it. On failure the error points at the
+/// text the parser stopped at, `reason` describes what was expected.
+pub fn parse_line<'a, T>(
+    line_number: usize,
+    line: &'a str,
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, T>,
+    reason: &str,
+) -> Result<T, ParseError> {
+    let rest = match parser(line) {
+        Ok(("", result)) => return Ok(result),
+        Ok((remainder, _)) => remainder,
+        Err(nom::Err::Error(error) | nom::Err::Failure(error)) => error.input,
+        Err(nom::Err::Incomplete(_)) => &line[line.len()..],
+    };
+
+    Err(ParseError::at(line_number, line, rest.trim_start(), reason))
+}
+
+/// Parses every line of `input` with `parser`, see [`parse_line`].
+pub fn lines<'a, T>(
+    input: &'a str,
+    parser: impl FnMut(&'a str) -> IResult<&'a str, T>,
+    reason: &str,
+) -> Result<Vec<T>, ParseError> {
+    Section {
+        first_line: 1,
+        text: input,
+    }
+    .lines(parser, reason)
+}
+
+/// A block of consecutive non-blank lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Section<'a> {
+    /// 1-based line number of the first line within the whole input.
+    pub first_line: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Section<'a> {
+    /// Parses every line of the section with `parser`, see [`parse_line`]. Line numbers in
+    /// errors are relative to the whole input.
+    pub fn lines<T>(
+        &self,
+        mut parser: impl FnMut(&'a str) -> IResult<&'a str, T>,
+        reason: &str,
+    ) -> Result<Vec<T>, ParseError> {
+        self.text
+            .lines()
+            .enumerate()
+            .map(|(index, line)| parse_line(self.first_line + index, line, &mut parser, reason))
+            .collect()
+    }
+}
+
+/// Splits `input` into sections separated by one or more blank lines.
+pub fn sections(input: &str) -> Vec<Section<'_>> {
+    let mut sections = vec![];
+    // Line number and byte offset of the start of the current section.
+    let mut start: Option<(usize, usize)> = None;
+    let mut offset = 0;
+
+    for (index, line) in input.split_inclusive('\n').enumerate() {
+        if line.trim().is_empty() {
+            if let Some((first_line, from)) = start.take() {
+                sections.push(Section {
+                    first_line,
+                    text: &input[from..offset],
+                });
+            }
+        } else if start.is_none() {
+            start = Some((index + 1, offset));
+        }
+        offset += line.len();
+    }
+
+    if let Some((first_line, from)) = start {
+        sections.push(Section {
+            first_line,
+            text: &input[from..],
+        });
+    }
+
+    sections
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{number, number_list};
+    use nom::bytes::complete::tag;
+
+    #[test]
+    fn test_parse_line_error_location() {
+        let error = parse_line(
+            3,
+            "1,2,x",
+            number_list::<usize, _>(tag(",")),
+            "expected pages",
+        )
+        .err()
+        .unwrap();
+        assert_eq!(error, ParseError::new(3, 4, ",x", "expected pages"));
+
+        let error = parse_line(1, "12 ", number::<usize>, "expected a number")
+            .err()
+            .unwrap();
+        assert_eq!((error.column, error.text.as_str()), (4, ""));
+    }
+
+    #[test]
+    fn test_sections() {
+        let input = "\n47|53\n97|13\n\n\n75,47\r\n61,53\n";
+        let sections = sections(input);
+
+        assert_eq!(
+            sections,
+            vec![
+                Section {
+                    first_line: 2,
+                    text: "47|53\n97|13\n"
+                },
+                Section {
+                    first_line: 6,
+                    text: "75,47\r\n61,53\n"
+                },
+            ]
+        );
+
+        let error = sections[1]
+            .lines(number::<usize>, "expected a page")
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (6, 3));
+    }
+}
@@ -0,0 +1,158 @@
+//! Command line options for the days that can visualize their solution.
+
+use std::fs::File;
+use std::io::{self, BufWriter};
+use std::path::PathBuf;
+use std::time::Duration;
+
+use aoc_core::Error;
+use clap::ValueEnum;
+
+use crate::sink::{Asciicast, Every, ImageFormat, Images, Sink, Terminal};
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    /// Play the animation in the terminal
+    Ansi,
+    /// Record the animation to play back with `asciinema play`
+    Asciicast,
+    /// Write a PPM image per frame
+    Ppm,
+    /// Write a PNG image per frame
+    Png,
+}
+
+/// Options to visualize a solution. Flatten this into the `Args` of a binary.
+#[derive(clap::Args, Debug, Clone, PartialEq)]
+pub struct VizArgs {
+    /// Animate how the solution is found, before printing the answers
+    #[arg(long, value_enum)]
+    pub visualize: Option<Format>,
+    /// File to record the animation to, or directory to write the images to. The `ansi`
+    /// animation plays on stdout when it is not given
+    #[arg(long, value_hint(clap::ValueHint::AnyPath))]
+    pub visualize_output: Option<PathBuf>,
+    /// Milliseconds every frame is shown
+    #[arg(long, default_value_t = 50)]
+    pub frame_delay: u64,
+    /// Keep one of every N frames. Defaults to what suits the day
+    #[arg(long, value_parser = clap::value_parser!(u16).range(1..))]
+    pub frame_every: Option<u16>,
+    /// Width of a cell in pixels, for images
+    #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u16).range(1..))]
+    pub frame_scale: u16,
+}
+
+impl VizArgs {
+    /// Whether the frames are written to stdout.
+    pub fn writes_stdout(&self) -> bool {
+        self.visualize == Some(Format::Ansi) && self.visualize_output.is_none()
+    }
+
+    /// Whether the options go together: every format but `ansi` needs an output.
+    pub fn check(&self) -> Result<(), String> {
+        match self.visualize {
+            Some(format) if format != Format::Ansi && self.visualize_output.is_none() => {
+                let format = format.to_possible_value().expect("Formats are not skipped");
+                Err(format!(
+                    "--visualize {} needs --visualize-output",
+                    format.get_name()
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Where the frames go, `None` when no visualization is asked for. Keeps one of every
+    /// `every` frames, unless `--frame-every` says otherwise.
+    pub fn open(&self, every: usize) -> Result<Option<Box<dyn Sink>>, String> {
+        self.check()?;
+        let Some(format) = self.visualize else {
+            return Ok(None);
+        };
+
+        let delay = Duration::from_millis(self.frame_delay);
+        let output = self.visualize_output.as_ref();
+        let sink: Box<dyn Sink> = match (format, output) {
+            (Format::Ansi, None) => Box::new(Terminal::animated(io::stdout(), delay)),
+            (Format::Ansi, Some(path)) => Box::new(Terminal::animated(create(path)?, delay)),
+            (Format::Asciicast, Some(path)) => Box::new(Asciicast::new(create(path)?, delay)),
+            (Format::Ppm | Format::Png, Some(dir)) => {
+                let image_format = if format == Format::Ppm {
+                    ImageFormat::Ppm
+                } else {
+                    ImageFormat::Png
+                };
+                let images = Images::new(dir.clone(), image_format, self.frame_scale.into());
+                Box::new(images.map_err(|e| format!("unable to create {}: {e}", dir.display()))?)
+            }
+            (_, None) => unreachable!("Checked above"),
+        };
+
+        let every = self.frame_every.map_or(every, usize::from);
+        Ok(Some(Box::new(Every::new(sink, every))))
+    }
+
+    /// Opens the sink like [`open`](VizArgs::open) and has `animate` draw into it. Does nothing
+    /// when no visualization is asked for.
+    pub fn animate(
+        &self,
+        every: usize,
+        animate: impl FnOnce(&mut dyn Sink) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        let sink = self
+            .open(every)
+            .map_err(|reason| Error::Output(io::Error::other(reason)))?;
+        let Some(mut sink) = sink else {
+            return Ok(());
+        };
+
+        animate(&mut sink)?;
+        sink.finish().map_err(Error::Output)
+    }
+}
+
+fn create(path: &PathBuf) -> Result<BufWriter<File>, String> {
+    File::create(path)
+        .map(BufWriter::new)
+        .map_err(|e| format!("unable to create {}: {e}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+
+    #[derive(Parser)]
+    struct Args {
+        #[command(flatten)]
+        viz: VizArgs,
+    }
+
+    fn open(args: &[&str]) -> Result<Option<Box<dyn Sink>>, String> {
+        let args = Args::try_parse_from(["day"].iter().chain(args)).unwrap();
+        args.viz.open(1)
+    }
+
+    #[test]
+    fn test_open() {
+        assert!(open(&[]).unwrap().is_none());
+        assert!(open(&["--visualize", "ansi"]).unwrap().is_some());
+        assert_eq!(
+            open(&["--visualize", "png"]).err(),
+            Some(String::from("--visualize png needs --visualize-output"))
+        );
+        assert!(Args::try_parse_from(["day", "--frame-every", "0"]).is_err());
+
+        let args = Args::try_parse_from(["day", "--visualize", "ppm"]).unwrap();
+        assert!(args.viz.check().is_err());
+        let mut called = false;
+        let animated = args.viz.animate(1, |_| {
+            called = true;
+            Ok(())
+        });
+        assert!(animated.is_err());
+        assert!(!called);
+    }
+}
@@ -0,0 +1,148 @@
+//! Visualizations of the grid days: a day renders the state of its grid into [`Frame`]s, and a
+//! [`Sink`] turns them into a terminal animation, an asciicast recording or a sequence of images.
+//!
+//! ```
+//! use aoc_grid::Grid;
+//! use aoc_viz::{Cell, Frame, Rgb, Sink, Terminal};
+//!
+//! let grid = Grid::parse("#.\n.#", |_, c| Ok(c)).unwrap();
+//! let frame = Frame::render(&grid, |_, &c| match c {
+//!     '#' => Cell::new('#', Rgb::WHITE, Rgb::BLACK),
+//!     _ => Cell::blank(Rgb::GREEN),
+//! });
+//!
+//! let mut out = vec![];
+//! Terminal::new(&mut out).frame(&frame).unwrap();
+//! assert!(String::from_utf8(out).unwrap().contains('#'));
+//! ```
+
+use std::fmt::{self, Display, Formatter};
+
+use aoc_grid::{Coord, Grid};
+
+mod cli;
+mod sink;
+
+pub use cli::{Format, VizArgs};
+pub use sink::{Asciicast, Every, ImageFormat, Images, Sink, Terminal};
+
+/// A 24-bit colour.
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const GREY: Rgb = Rgb(96, 96, 96);
+    pub const RED: Rgb = Rgb(205, 49, 49);
+    pub const GREEN: Rgb = Rgb(13, 188, 121);
+    pub const YELLOW: Rgb = Rgb(229, 229, 16);
+    pub const BLUE: Rgb = Rgb(36, 114, 200);
+    pub const MAGENTA: Rgb = Rgb(188, 63, 188);
+    pub const CYAN: Rgb = Rgb(41, 184, 219);
+}
+
+/// One tile of a frame: a character drawn in `fg` on `bg`.
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
+pub struct Cell {
+    pub glyph: char,
+    pub fg: Rgb,
+    pub bg: Rgb,
+}
+
+impl Cell {
+    pub fn new(glyph: char, fg: Rgb, bg: Rgb) -> Cell {
+        Cell { glyph, fg, bg }
+    }
+
+    /// A cell without a character, only showing `bg`.
+    pub fn blank(bg: Rgb) -> Cell {
+        Cell::new(' ', Rgb::WHITE, bg)
+    }
+}
+
+/// One picture of an animation: a grid of cells with a line of text below it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Frame {
+    pub cells: Grid<Cell>,
+    pub caption: String,
+}
+
+impl Frame {
+    /// Frame with a cell for every cell of `grid`, drawn by `cell(coord, value)`.
+    pub fn render<T>(grid: &Grid<T>, mut cell: impl FnMut(Coord, &T) -> Cell) -> Frame {
+        let cells = grid
+            .iter()
+            .map(|(coord, value)| cell(coord, value))
+            .collect();
+
+        Frame {
+            cells: Grid::from_cells(grid.width(), cells),
+            caption: String::new(),
+        }
+    }
+
+    pub fn with_caption(mut self, caption: impl Into<String>) -> Frame {
+        self.caption = caption.into();
+        self
+    }
+
+    /// Lines the frame takes up in a terminal, the caption included.
+    pub fn height(&self) -> usize {
+        self.cells.height() + usize::from(!self.caption.is_empty())
+    }
+
+    /// Columns the frame takes up in a terminal, the caption included.
+    pub fn width(&self) -> usize {
+        self.cells.width().max(self.caption.chars().count())
+    }
+}
+
+/// Draws the frame with ANSI escape codes for 24-bit colours, one line per row.
+impl Display for Frame {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for row in self.cells.rows() {
+            let mut colours = None;
+            for cell in row {
+                if colours != Some((cell.fg, cell.bg)) {
+                    let (Rgb(r, g, b), Rgb(br, bg, bb)) = (cell.fg, cell.bg);
+                    write!(f, "\x1b[38;2;{r};{g};{b};48;2;{br};{bg};{bb}m")?;
+                    colours = Some((cell.fg, cell.bg));
+                }
+                write!(f, "{}", cell.glyph)?;
+            }
+            writeln!(f, "\x1b[0m")?;
+        }
+
+        if !self.caption.is_empty() {
+            writeln!(f, "{}", self.caption)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    pub fn example() -> Frame {
+        let grid = Grid::parse("ab\n.c", |_, c| Ok(c)).unwrap();
+        Frame::render(&grid, |_, &c| match c {
+            '.' => Cell::blank(Rgb::BLACK),
+            c => Cell::new(c, Rgb::WHITE, Rgb::BLUE),
+        })
+    }
+
+    #[test]
+    fn test_ansi() {
+        let frame = example().with_caption("step 1");
+
+        assert_eq!(
+            frame.to_string(),
+            "\x1b[38;2;255;255;255;48;2;36;114;200mab\x1b[0m\n\
+             \x1b[38;2;255;255;255;48;2;0;0;0m \x1b[38;2;255;255;255;48;2;36;114;200mc\x1b[0m\n\
+             step 1\n"
+        );
+        assert_eq!((frame.width(), frame.height()), (6, 3));
+    }
+}
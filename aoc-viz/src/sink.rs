@@ -0,0 +1,327 @@
+//! Where frames go: a terminal, an asciicast recording or a directory of images.
+
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+use std::thread;
+use std::time::Duration;
+
+use aoc_core::report::json_string;
+
+use crate::{Frame, Rgb};
+
+/// Takes the frames of an animation, in order.
+pub trait Sink {
+    fn frame(&mut self, frame: &Frame) -> io::Result<()>;
+
+    /// Called after the last frame.
+    fn finish(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<S: Sink + ?Sized> Sink for Box<S> {
+    fn frame(&mut self, frame: &Frame) -> io::Result<()> {
+        (**self).frame(frame)
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        (**self).finish()
+    }
+}
+
+/// Prints frames with ANSI escape codes. Without a delay, frames are printed one below the
+/// other. With one, every frame replaces the previous one on screen and stays for the delay.
+pub struct Terminal<W> {
+    out: W,
+    delay: Option<Duration>,
+}
+
+impl<W: Write> Terminal<W> {
+    pub fn new(out: W) -> Terminal<W> {
+        Terminal { out, delay: None }
+    }
+
+    /// Animates in place, showing every frame for `delay`.
+    pub fn animated(out: W, delay: Duration) -> Terminal<W> {
+        Terminal {
+            out,
+            delay: Some(delay),
+        }
+    }
+}
+
+impl<W: Write> Sink for Terminal<W> {
+    fn frame(&mut self, frame: &Frame) -> io::Result<()> {
+        match self.delay {
+            Some(delay) => {
+                write!(self.out, "\x1b[H\x1b[2J{frame}")?;
+                self.out.flush()?;
+                thread::sleep(delay);
+            }
+            None => writeln!(self.out, "{frame}")?,
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        self.out.flush()
+    }
+}
+
+/// Records frames in the [asciicast v2] format, to play back with `asciinema play`.
+///
+/// [asciicast v2]: https://docs.asciinema.org/manual/asciicast/v2/
+pub struct Asciicast<W> {
+    out: W,
+    interval: Duration,
+    time: Duration,
+    started: bool,
+}
+
+impl<W: Write> Asciicast<W> {
+    /// Recording that shows every frame for `interval`.
+    pub fn new(out: W, interval: Duration) -> Asciicast<W> {
+        Asciicast {
+            out,
+            interval,
+            time: Duration::ZERO,
+            started: false,
+        }
+    }
+}
+
+impl<W: Write> Sink for Asciicast<W> {
+    fn frame(&mut self, frame: &Frame) -> io::Result<()> {
+        // The size of the terminal is taken from the first frame, so every frame should have it.
+        if !self.started {
+            writeln!(
+                self.out,
+                "{{\"version\": 2, \"width\": {}, \"height\": {}}}",
+                frame.width(),
+                frame.height()
+            )?;
+            self.started = true;
+        }
+
+        let screen = format!("\x1b[H\x1b[2J{frame}").replace('\n', "\r\n");
+        writeln!(
+            self.out,
+            "[{:.6}, \"o\", {}]",
+            self.time.as_secs_f64(),
+            json_string(&screen)
+        )?;
+        self.time += self.interval;
+        Ok(())
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        self.out.flush()
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum ImageFormat {
+    /// Binary portable pixmap, `P6`.
+    Ppm,
+    Png,
+}
+
+/// Writes every frame as an image of its own, `frame-00000.ppm` and up, into a directory. Every
+/// cell is a square of pixels in its background colour, with a smaller square in its foreground
+/// colour when it has a character. Captions are left out.
+pub struct Images {
+    dir: PathBuf,
+    format: ImageFormat,
+    scale: usize,
+    count: usize,
+}
+
+impl Images {
+    /// Writes into `dir`, which is created when missing, drawing cells `scale` pixels wide.
+    pub fn new(dir: PathBuf, format: ImageFormat, scale: usize) -> io::Result<Images> {
+        fs::create_dir_all(&dir)?;
+
+        Ok(Images {
+            dir,
+            format,
+            scale: scale.max(1),
+            count: 0,
+        })
+    }
+
+    /// The pixels of `frame` as RGB bytes, row by row.
+    fn pixels(&self, frame: &Frame) -> (usize, usize, Vec<u8>) {
+        let scale = self.scale;
+        let (width, height) = (frame.cells.width() * scale, frame.cells.height() * scale);
+        let margin = scale / 4;
+        let mut pixels = Vec::with_capacity(width * height * 3);
+
+        for y in 0..height {
+            let row = frame.cells.row(y / scale);
+            let inner_y = (margin..scale - margin).contains(&(y % scale));
+            for x in 0..width {
+                let cell = row[x / scale];
+                let inner = inner_y && (margin..scale - margin).contains(&(x % scale));
+                let Rgb(r, g, b) = if inner && margin > 0 && cell.glyph != ' ' {
+                    cell.fg
+                } else {
+                    cell.bg
+                };
+                pixels.extend([r, g, b]);
+            }
+        }
+
+        (width, height, pixels)
+    }
+}
+
+impl Sink for Images {
+    fn frame(&mut self, frame: &Frame) -> io::Result<()> {
+        let (width, height, pixels) = self.pixels(frame);
+        let extension = match self.format {
+            ImageFormat::Ppm => "ppm",
+            ImageFormat::Png => "png",
+        };
+        let path = self
+            .dir
+            .join(format!("frame-{:05}.{extension}", self.count));
+        let mut out = BufWriter::new(File::create(path)?);
+
+        match self.format {
+            ImageFormat::Ppm => {
+                write!(out, "P6\n{width} {height}\n255\n")?;
+                out.write_all(&pixels)?;
+            }
+            ImageFormat::Png => {
+                let size = |n: usize| u32::try_from(n).map_err(io::Error::other);
+                let mut encoder = png::Encoder::new(&mut out, size(width)?, size(height)?);
+                encoder.set_color(png::ColorType::Rgb);
+                encoder.set_depth(png::BitDepth::Eight);
+                let mut writer = encoder.write_header().map_err(io::Error::other)?;
+                writer.write_image_data(&pixels).map_err(io::Error::other)?;
+                writer.finish().map_err(io::Error::other)?;
+            }
+        }
+
+        self.count += 1;
+        out.flush()
+    }
+}
+
+/// Passes on one of every `every` frames, and always the last one, so long animations can be
+/// shortened without losing their end.
+pub struct Every<S> {
+    sink: S,
+    every: usize,
+    seen: usize,
+    skipped: Option<Frame>,
+}
+
+impl<S: Sink> Every<S> {
+    pub fn new(sink: S, every: usize) -> Every<S> {
+        Every {
+            sink,
+            every: every.max(1),
+            seen: 0,
+            skipped: None,
+        }
+    }
+}
+
+impl<S: Sink> Sink for Every<S> {
+    fn frame(&mut self, frame: &Frame) -> io::Result<()> {
+        let pass = self.seen.is_multiple_of(self.every);
+        self.seen += 1;
+
+        if pass {
+            self.skipped = None;
+            self.sink.frame(frame)
+        } else {
+            self.skipped = Some(frame.clone());
+            Ok(())
+        }
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        if let Some(frame) = self.skipped.take() {
+            self.sink.frame(&frame)?;
+        }
+        self.sink.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+    use crate::tests::example;
+
+    /// Keeps the captions of the frames it gets.
+    #[derive(Default)]
+    struct Captions(Vec<String>);
+
+    impl Sink for Captions {
+        fn frame(&mut self, frame: &Frame) -> io::Result<()> {
+            self.0.push(frame.caption.clone());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_every() {
+        let mut every = Every::new(Captions::default(), 3);
+        for step in 0..8 {
+            every
+                .frame(&example().with_caption(step.to_string()))
+                .unwrap();
+        }
+        every.finish().unwrap();
+
+        assert_eq!(every.sink.0, ["0", "3", "6", "7"]);
+    }
+
+    #[test]
+    fn test_asciicast() {
+        let mut cast = Asciicast::new(vec![], Duration::from_millis(250));
+        cast.frame(&example()).unwrap();
+        cast.frame(&example().with_caption("done")).unwrap();
+
+        let text = String::from_utf8(cast.out).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], "{\"version\": 2, \"width\": 2, \"height\": 2}");
+        assert!(lines[1].starts_with("[0.000000, \"o\", \"\\u001b[H\\u001b[2J\\u001b[38;2;"));
+        assert!(lines[1].ends_with("\\u001b[0m\\r\\n\"]"));
+        assert!(lines[2].starts_with("[0.250000, \"o\", "));
+        assert!(lines[2].ends_with("done\\r\\n\"]"));
+    }
+
+    #[test]
+    fn test_images() {
+        let dir = env::temp_dir().join(format!("aoc-viz-images-{}", std::process::id()));
+        let mut ppm = Images::new(dir.clone(), ImageFormat::Ppm, 4).unwrap();
+        ppm.frame(&example()).unwrap();
+        ppm.frame(&example()).unwrap();
+        let mut png = Images::new(dir.clone(), ImageFormat::Png, 1).unwrap();
+        png.frame(&example()).unwrap();
+
+        let first = fs::read(dir.join("frame-00000.ppm")).unwrap();
+        let second = dir.join("frame-00001.ppm").is_file();
+        let png = fs::read(dir.join("frame-00000.png")).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        let header = b"P6\n8 8\n255\n";
+        assert!(first.starts_with(header));
+        let pixels = &first[header.len()..];
+        assert_eq!(pixels.len(), 8 * 8 * 3);
+        // Top left corner of `a` is background, its middle the character.
+        assert_eq!(pixels[..3], [36, 114, 200]);
+        assert_eq!(pixels[(8 + 1) * 3..(8 + 1) * 3 + 3], [255, 255, 255]);
+        // The blank cell has no character.
+        assert_eq!(pixels[(5 * 8 + 1) * 3..(5 * 8 + 1) * 3 + 3], [0, 0, 0]);
+        assert!(second);
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+    }
+}
@@ -0,0 +1,77 @@
+//! Criterion benchmarks of the parser and both parts of every day, on the example from the
+//! day's `readme.md` and on the checked-in puzzle input.
+//!
+//! ```text
+//! cargo bench -p aoc --bench solutions                            # HTML report in target/criterion
+//! cargo bench -p aoc --bench solutions -- day06                   # a single day
+//! cargo bench -p aoc --bench solutions -- --save-baseline before  # record a baseline
+//! cargo bench -p aoc --bench solutions -- --baseline before       # compare against it
+//! ```
+
+use std::hint::black_box;
+
+use aoc_core::examples::parse_examples;
+use aoc_core::Solution;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+/// The example used for benchmarking: the first one in the readme that checks part 2, so both
+/// parts run on input they are meant for.
+fn example(readme: &str) -> String {
+    parse_examples(readme)
+        .expect("Readme examples should be well-formed")
+        .into_iter()
+        .find(|example| example.part_2.is_some())
+        .expect("Readme should have an example for part 2")
+        .input
+}
+
+fn bench_day<S: Solution>(c: &mut Criterion, readme: &str, input: &str) {
+    let mut group = c.benchmark_group(format!("day{:02}", S::DAY));
+    let example = example(readme);
+
+    for (name, text) in [("example", example.as_str()), ("input", input)] {
+        let parsed = S::parse(text).expect("Benchmark input should parse");
+
+        // Part 2 of some days takes a good fraction of a second on the real input.
+        group.sample_size(if name == "input" { 10 } else { 100 });
+
+        group.bench_function(format!("{name}/parse"), |b| {
+            b.iter(|| S::parse(black_box(text)))
+        });
+        group.bench_function(format!("{name}/part1"), |b| {
+            b.iter(|| S::part1(black_box(&parsed)))
+        });
+        group.bench_function(format!("{name}/part2"), |b| {
+            b.iter(|| S::part2(black_box(&parsed)))
+        });
+    }
+
+    group.finish();
+}
+
+macro_rules! bench_days {
+    ($($day:ident => $solution:ty),* $(,)?) => {
+        $(
+            fn $day(c: &mut Criterion) {
+                bench_day::<$solution>(
+                    c,
+                    include_str!(concat!("../../", stringify!($day), "/readme.md")),
+                    include_str!(concat!("../../", stringify!($day), "/input.txt")),
+                );
+            }
+        )*
+    };
+}
+
+bench_days! {
+    day01 => day01::Day01,
+    day02 => day02::Day02,
+    day03 => day03::Day03,
+    day04 => day04::Day04,
+    day05 => day05::Day05,
+    day06 => day06::Day06,
+    day07 => day07::Day07,
+}
+
+criterion_group!(benches, day01, day02, day03, day04, day05, day06, day07);
+criterion_main!(benches);
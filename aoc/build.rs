@@ -0,0 +1,113 @@
+//! Hashes the sources of every day, together with the workspace crates it depends on, into
+//! `source_hashes.rs`. The answer cache uses the hash as the version of a day's solver, so it
+//! never hands out answers computed by a solver that has changed since.
+
+use std::collections::BTreeSet;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+#[path = "src/fnv.rs"]
+mod fnv;
+
+use fnv::Fnv;
+
+/// Every file below `dir`, sorted so the hash does not depend on the order of the file system.
+fn files(dir: &Path, found: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            files(&path, found)?;
+        } else {
+            found.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Adds the crate in `dir` and the crates it depends on by path, directly or not, to `found`.
+/// Dev-dependencies are left out, as they do not change what the crate computes.
+fn local_crates(dir: &Path, found: &mut BTreeSet<PathBuf>) -> io::Result<()> {
+    let dir = dir.canonicalize()?;
+    if found.contains(&dir) {
+        return Ok(());
+    }
+
+    let manifest = fs::read_to_string(dir.join("Cargo.toml"))?;
+    let manifest: toml::Table = toml::from_str(&manifest).map_err(io::Error::other)?;
+    found.insert(dir.clone());
+
+    let dependencies = manifest.get("dependencies").and_then(|d| d.as_table());
+    for dependency in dependencies.into_iter().flat_map(|d| d.values()) {
+        if let Some(path) = dependency.get("path").and_then(|p| p.as_str()) {
+            local_crates(&dir.join(path), found)?;
+        }
+    }
+    Ok(())
+}
+
+/// Hashes the manifest and sources of the crate in `dir`, and asks cargo to rerun when they
+/// change.
+fn hash_crate(root: &Path, dir: &Path, hasher: &mut Fnv) -> io::Result<()> {
+    let mut found = vec![dir.join("Cargo.toml")];
+    files(&dir.join("src"), &mut found)?;
+    found[1..].sort();
+
+    for path in found {
+        // Joined with `/` on every platform, so the hash does not depend on it.
+        let relative: Vec<_> = path
+            .strip_prefix(root)
+            .unwrap_or(&path)
+            .iter()
+            .map(|part| part.to_string_lossy())
+            .collect();
+        hasher.field(relative.join("/").as_bytes());
+        hasher.field(&fs::read(&path)?);
+    }
+
+    println!(
+        "cargo::rerun-if-changed={}",
+        dir.join("Cargo.toml").display()
+    );
+    println!("cargo::rerun-if-changed={}", dir.join("src").display());
+    Ok(())
+}
+
+fn main() -> io::Result<()> {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let root = manifest_dir.parent().unwrap().canonicalize()?;
+    let root = root.as_path();
+    // `aoc new` adds days to the workspace manifest.
+    println!(
+        "cargo::rerun-if-changed={}",
+        root.join("Cargo.toml").display()
+    );
+
+    let mut days = vec![];
+    for entry in fs::read_dir(root)? {
+        let name = entry?.file_name().to_string_lossy().into_owned();
+        let number = name.strip_prefix("day").and_then(|n| n.parse::<u8>().ok());
+        if let Some(number) = number.filter(|_| root.join(&name).join("Cargo.toml").is_file()) {
+            let mut crates = BTreeSet::new();
+            local_crates(&root.join(&name), &mut crates)?;
+
+            let mut hasher = Fnv::default();
+            for dir in &crates {
+                hash_crate(root, dir, &mut hasher)?;
+            }
+            days.push((number, hasher.finish()));
+        }
+    }
+    days.sort();
+
+    let entries: String = days
+        .iter()
+        .map(|(number, hash)| format!("    ({number}, {hash:#018x}),\n"))
+        .collect();
+    let out = PathBuf::from(env::var("OUT_DIR").unwrap()).join("source_hashes.rs");
+    fs::write(
+        out,
+        format!("/// Hash of the sources of every day.\npub const SOURCE_HASHES: &[(u8, u64)] = &[\n{entries}];\n"),
+    )
+}
@@ -0,0 +1,121 @@
+//! Answers of earlier runs, stored on disk by the hash of the input and the solver.
+//!
+//! The solver is identified by a hash of the sources of its day and the workspace crates it uses,
+//! taken at build time, so editing a solver makes its cached answers unreachable. Every answer is
+//! kept in a file like `dayNN/.answers`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc_core::timings::Timings;
+use aoc_core::Answers;
+
+use crate::fnv::Fnv;
+use crate::site::CACHE_DIR;
+use crate::verify;
+
+include!(concat!(env!("OUT_DIR"), "/source_hashes.rs"));
+
+/// The version of the solver of `day`, 0 for a day the runner was built without.
+fn source_hash(day: u8) -> u64 {
+    SOURCE_HASHES
+        .iter()
+        .find(|(number, _)| *number == day)
+        .map_or(0, |(_, hash)| *hash)
+}
+
+/// Identifies the answers to `input` of `day` by the solver with hash `source`.
+fn key(day: u8, source: u64, input: &str) -> u64 {
+    Fnv::default()
+        .field(&[day])
+        .field(&source.to_le_bytes())
+        .field(&[u8::from(cfg!(feature = "wide"))])
+        .field(input.as_bytes())
+        .finish()
+}
+
+pub struct AnswerCache {
+    dir: PathBuf,
+}
+
+impl AnswerCache {
+    pub fn new(dir: PathBuf) -> AnswerCache {
+        AnswerCache { dir }
+    }
+
+    /// The cache in the cache directory below the workspace `root`.
+    pub fn in_workspace(root: &Path) -> AnswerCache {
+        AnswerCache::new(root.join(CACHE_DIR).join("answers"))
+    }
+
+    fn path(&self, day: u8, input: &str) -> PathBuf {
+        let key = key(day, source_hash(day), input);
+        self.dir
+            .join(format!("day{day:02}"))
+            .join(format!("{key:016x}.txt"))
+    }
+
+    /// The answers stored for `input` of `day`, without timings. Anything wrong with the cache
+    /// counts as a miss.
+    pub fn get(&self, day: u8, input: &str) -> Option<Answers> {
+        let expected = verify::read_expected(&self.path(day, input)).ok()?;
+        Some(Answers {
+            day,
+            part_1: expected.part_1?,
+            part_2: expected.part_2?,
+            timings: Timings::default(),
+        })
+    }
+
+    /// Stores the answers to `input`.
+    pub fn put(&self, input: &str, answers: &Answers) -> Result<(), String> {
+        let path = self.path(answers.day, input);
+        let dir = path.parent().expect("Cache files are in a directory");
+        fs::create_dir_all(dir).map_err(|e| format!("unable to create {}: {e}", dir.display()))?;
+
+        let text = format!("part1: {}\npart2: {}\n", answers.part_1, answers.part_2);
+        fs::write(&path, text).map_err(|e| format!("unable to write {}: {e}", path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    #[test]
+    fn test_source_hashes() {
+        for day in crate::days::DAYS {
+            assert_ne!(source_hash(day.number), 0, "day {}", day.number);
+        }
+        assert_eq!(source_hash(25), 0);
+        assert_ne!(key(1, 1, "1 2"), key(1, 2, "1 2"));
+        assert_ne!(key(1, 1, "1 2"), key(2, 1, "1 2"));
+        assert_ne!(key(1, 1, "1 2"), key(1, 1, "1 3"));
+    }
+
+    #[test]
+    fn test_get_put() {
+        let dir = env::temp_dir().join(format!("aoc-cache-{}", std::process::id()));
+        let cache = AnswerCache::new(dir.clone());
+        let answers = Answers {
+            day: 1,
+            part_1: String::from("11"),
+            part_2: String::from("31"),
+            timings: Timings::default(),
+        };
+
+        let before = cache.get(1, "3   4\n");
+        cache.put("3   4\n", &answers).unwrap();
+        let hit = cache.get(1, "3   4\n");
+        let other_input = cache.get(1, "3   5\n");
+        let other_day = cache.get(2, "3   4\n");
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(before, None);
+        assert_eq!(hit, Some(answers));
+        assert_eq!(other_input, None);
+        assert_eq!(other_day, None);
+    }
+}
@@ -0,0 +1,770 @@
+//! `aoc dashboard`: a terminal UI listing every day with the title of its puzzle and its last
+//! answers, to run a part, change the input and play a visualization without leaving the
+//! terminal.
+//!
+//! Solvers and visualizations run on threads of their own and report back over channels, so the
+//! screen keeps responding while they work.
+
+use std::fs;
+use std::io::{self, IsTerminal};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, Sender, SyncSender};
+use std::thread;
+use std::time::Duration;
+
+use aoc_core::{read_input, Answers, PartAnswer};
+use aoc_viz::{Frame, Rgb, Sink};
+use ratatui::buffer::Buffer;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Style, Stylize};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Cell, Paragraph, Row, Table, TableState, Widget};
+use ratatui::DefaultTerminal;
+
+use crate::cache::AnswerCache;
+use crate::days::Day;
+use crate::pool::panic_message;
+
+/// How often the screen is redrawn, and so how long a frame of a visualization is shown.
+const TICK: Duration = Duration::from_millis(50);
+/// Frames a visualization may get ahead of the viewer.
+const FRAME_BUFFER: usize = 64;
+/// Most frames a visualization is moved ahead by per tick.
+const MAX_SPEED: usize = 1024;
+
+const HELP: &str = "↑↓ select  r run  1/2 run a part  i input  v visualize  q quit";
+const VIEWER_HELP: &str = "space pause  +/- speed  ←↑↓→ scroll  esc back";
+
+/// Shows the dashboard for `days`, each solved for the input next to it, until it is quit. The
+/// titles are read from the readmes in the workspace `root`.
+pub fn run(
+    days: Vec<(&'static Day, PathBuf)>,
+    root: &Path,
+    cache: AnswerCache,
+) -> Result<(), String> {
+    if !io::stdout().is_terminal() {
+        return Err(String::from("the dashboard needs a terminal"));
+    }
+
+    let mut app = App::new(days, root, cache);
+    let mut terminal = ratatui::init();
+    let restore = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        // Solvers and visualizations panic on threads of their own, and report it on screen.
+        if thread::current().name() == Some("main") {
+            restore(info);
+        }
+    }));
+
+    let shown = app.show(&mut terminal);
+    ratatui::restore();
+    shown.map_err(|e| format!("unable to draw the dashboard: {e}"))
+}
+
+/// The title of the puzzle on the first line of a `readme.md`, like `Guard Gallivant` for
+/// `# Day 6: Guard Gallivant`.
+fn readme_title(readme: &str) -> Option<&str> {
+    let heading = readme.lines().next()?.strip_prefix("# ")?;
+    let (_, title) = heading.split_once(": ")?;
+    Some(title.trim())
+}
+
+/// What the dashboard knows of the answer to one part.
+#[derive(Debug, Clone, PartialEq)]
+enum Answer {
+    Unknown,
+    Running,
+    /// From the answer cache, which keeps no times.
+    Cached(String),
+    Solved {
+        answer: String,
+        time: Duration,
+    },
+    Failed(String),
+}
+
+/// A line of the list: a day, the input it is solved for and its last answers.
+struct Entry {
+    day: &'static Day,
+    title: String,
+    input: PathBuf,
+    answers: [Answer; 2],
+    /// Time the last run took to parse the input.
+    parse: Option<Duration>,
+}
+
+/// What a solver thread reports back.
+struct Solved {
+    index: usize,
+    input: PathBuf,
+    /// The part solved, both when `None`.
+    part: Option<u8>,
+    outcome: Result<Found, String>,
+}
+
+enum Found {
+    /// Answers to both parts, with the input they are for to cache them.
+    Both {
+        answers: Answers,
+        text: String,
+    },
+    Part(PartAnswer),
+}
+
+enum Mode {
+    Browse,
+    /// Typing the path of another input for the selected day.
+    EditInput(String),
+    Viewing(Viewer),
+}
+
+struct App {
+    entries: Vec<Entry>,
+    table: TableState,
+    mode: Mode,
+    /// The outcome of the last action, shown instead of the help until the next key.
+    status: String,
+    cache: AnswerCache,
+    sender: Sender<Solved>,
+    receiver: Receiver<Solved>,
+    quit: bool,
+}
+
+impl App {
+    fn new(days: Vec<(&'static Day, PathBuf)>, root: &Path, cache: AnswerCache) -> App {
+        let entries = days
+            .into_iter()
+            .map(|(day, input)| {
+                let readme =
+                    fs::read_to_string(root.join(format!("day{:02}/readme.md", day.number)));
+                let title = readme
+                    .ok()
+                    .and_then(|readme| readme_title(&readme).map(str::to_owned));
+                Entry {
+                    day,
+                    title: title.unwrap_or_default(),
+                    input,
+                    answers: [Answer::Unknown, Answer::Unknown],
+                    parse: None,
+                }
+            })
+            .collect();
+        let (sender, receiver) = mpsc::channel();
+
+        let mut app = App {
+            entries,
+            table: TableState::default().with_selected(0),
+            mode: Mode::Browse,
+            status: String::new(),
+            cache,
+            sender,
+            receiver,
+            quit: false,
+        };
+        for index in 0..app.entries.len() {
+            app.load_cached(index);
+        }
+        app
+    }
+
+    fn show(&mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
+        while !self.quit {
+            terminal.draw(|screen| self.draw(screen))?;
+            if event::poll(TICK)? {
+                if let Event::Key(key) = event::read()? {
+                    if key.kind == KeyEventKind::Press {
+                        self.handle_key(key);
+                    }
+                }
+            }
+
+            self.receive();
+            if let Mode::Viewing(viewer) = &mut self.mode {
+                viewer.advance();
+            }
+        }
+        Ok(())
+    }
+
+    fn selected(&self) -> usize {
+        self.table.selected().unwrap_or(0)
+    }
+
+    /// Forgets the answers of entry `index`, and shows the cached ones for its input if any.
+    fn load_cached(&mut self, index: usize) {
+        let entry = &mut self.entries[index];
+        let cached = read_input(&entry.input)
+            .ok()
+            .and_then(|input| self.cache.get(entry.day.number, &input));
+
+        entry.answers = match cached {
+            Some(answers) => [
+                Answer::Cached(answers.part_1),
+                Answer::Cached(answers.part_2),
+            ],
+            None => [Answer::Unknown, Answer::Unknown],
+        };
+        entry.parse = None;
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) {
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            self.quit = true;
+            return;
+        }
+        self.status.clear();
+
+        match &mut self.mode {
+            Mode::Browse => match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => self.quit = true,
+                KeyCode::Up | KeyCode::Char('k') => self.table.select_previous(),
+                KeyCode::Down | KeyCode::Char('j') => {
+                    let last = self.entries.len().saturating_sub(1);
+                    self.table.select(Some((self.selected() + 1).min(last)));
+                }
+                KeyCode::Char('r') => self.solve(None),
+                KeyCode::Char('1') => self.solve(Some(1)),
+                KeyCode::Char('2') => self.solve(Some(2)),
+                KeyCode::Char('i') => {
+                    let input = self.entries[self.selected()].input.display().to_string();
+                    self.mode = Mode::EditInput(input);
+                }
+                KeyCode::Char('v') => self.visualize(),
+                _ => {}
+            },
+            Mode::EditInput(input) => match key.code {
+                KeyCode::Char(c) => input.push(c),
+                KeyCode::Backspace => {
+                    input.pop();
+                }
+                KeyCode::Enter if !input.trim().is_empty() => {
+                    let input = PathBuf::from(input.trim());
+                    let index = self.selected();
+                    self.entries[index].input = input;
+                    self.mode = Mode::Browse;
+                    self.load_cached(index);
+                }
+                KeyCode::Esc => self.mode = Mode::Browse,
+                _ => {}
+            },
+            Mode::Viewing(viewer) => match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => self.mode = Mode::Browse,
+                KeyCode::Char(' ') => viewer.paused = !viewer.paused,
+                KeyCode::Char('+') => viewer.speed = (viewer.speed * 2).min(MAX_SPEED),
+                KeyCode::Char('-') => viewer.speed = (viewer.speed / 2).max(1),
+                KeyCode::Up => viewer.scroll(-1, 0),
+                KeyCode::Down => viewer.scroll(1, 0),
+                KeyCode::Left => viewer.scroll(0, -1),
+                KeyCode::Right => viewer.scroll(0, 1),
+                _ => {}
+            },
+        }
+    }
+
+    /// Solves `part` of the selected day, or both parts when `None`, on a thread of its own.
+    fn solve(&mut self, part: Option<u8>) {
+        let index = self.selected();
+        let entry = &mut self.entries[index];
+        if entry.answers.contains(&Answer::Running) {
+            self.status = format!("Day {} is already running", entry.day.number);
+            return;
+        }
+        for part in parts(part) {
+            entry.answers[part] = Answer::Running;
+        }
+
+        let (day, input, sender) = (entry.day, entry.input.clone(), self.sender.clone());
+        thread::spawn(move || {
+            let outcome = panic::catch_unwind(AssertUnwindSafe(|| {
+                let text = read_input(&input).map_err(|e| e.to_string())?;
+                let found = match part {
+                    None => (day.solve)(&text).map(|answers| Found::Both { answers, text }),
+                    Some(part) => (day.solve_part)(&text, part).map(Found::Part),
+                };
+                found.map_err(|e| e.to_string())
+            }));
+            let outcome = outcome.unwrap_or_else(|payload| {
+                Err(format!(
+                    "solver panicked: {}",
+                    panic_message(payload.as_ref())
+                ))
+            });
+
+            // Nobody is waiting for the answers any more when the dashboard was quit.
+            let _ = sender.send(Solved {
+                index,
+                input,
+                part,
+                outcome,
+            });
+        });
+    }
+
+    /// Takes in the answers of the solver threads that finished.
+    fn receive(&mut self) {
+        while let Ok(solved) = self.receiver.try_recv() {
+            let entry = &mut self.entries[solved.index];
+            if entry.input != solved.input {
+                // The input was changed while it was being solved.
+                continue;
+            }
+
+            match solved.outcome {
+                Ok(Found::Both { answers, text }) => {
+                    let timings = answers.timings;
+                    entry.parse = Some(timings.parse.time);
+                    entry.answers = [
+                        Answer::Solved {
+                            answer: answers.part_1.clone(),
+                            time: timings.part_1.time,
+                        },
+                        Answer::Solved {
+                            answer: answers.part_2.clone(),
+                            time: timings.part_2.time,
+                        },
+                    ];
+                    if let Err(error) = self.cache.put(&text, &answers) {
+                        self.status = format!("unable to cache the answers: {error}");
+                    }
+                }
+                Ok(Found::Part(answer)) => {
+                    entry.parse = Some(answer.parse.time);
+                    entry.answers[usize::from(answer.part - 1)] = Answer::Solved {
+                        answer: answer.answer,
+                        time: answer.solve.time,
+                    };
+                }
+                Err(error) => {
+                    for part in parts(solved.part) {
+                        entry.answers[part] = Answer::Failed(error.clone());
+                    }
+                    self.status = format!("Day {}: {error}", entry.day.number);
+                }
+            }
+        }
+    }
+
+    /// Plays the visualization of the selected day for its input.
+    fn visualize(&mut self) {
+        let entry = &self.entries[self.selected()];
+        let Some(visualize) = entry.day.visualize else {
+            self.status = format!("Day {} has no visualization", entry.day.number);
+            return;
+        };
+
+        let (sender, frames) = mpsc::sync_channel(FRAME_BUFFER);
+        let input = entry.input.clone();
+        thread::spawn(move || {
+            let mut channel = Channel(sender.clone());
+            let outcome = panic::catch_unwind(AssertUnwindSafe(|| {
+                let text = read_input(&input).map_err(|e| e.to_string())?;
+                visualize(&text, &mut channel).map_err(|e| e.to_string())
+            }));
+            let outcome = outcome.unwrap_or_else(|payload| {
+                Err(format!(
+                    "visualization panicked: {}",
+                    panic_message(payload.as_ref())
+                ))
+            });
+
+            // Nobody is watching any more when the viewer was closed.
+            let _ = sender.send(Shown::Finished(outcome));
+        });
+
+        let title = format!("Day {}: {}", entry.day.number, entry.title);
+        self.mode = Mode::Viewing(Viewer::new(title, frames));
+    }
+
+    fn draw(&mut self, screen: &mut ratatui::Frame) {
+        if let Mode::Viewing(viewer) = &self.mode {
+            viewer.draw(screen);
+            return;
+        }
+
+        let [list, details, footer] = Layout::vertical([
+            Constraint::Min(3),
+            Constraint::Length(6),
+            Constraint::Length(1),
+        ])
+        .areas(screen.area());
+
+        let rows = self.entries.iter().map(|entry| {
+            let [part_1, time_1] = answer_cells(&entry.answers[0]);
+            let [part_2, time_2] = answer_cells(&entry.answers[1]);
+            Row::new([
+                Cell::from(entry.day.number.to_string()),
+                Cell::from(entry.title.as_str()),
+                part_1,
+                time_1,
+                part_2,
+                time_2,
+            ])
+        });
+        let widths = [
+            Constraint::Length(3),
+            Constraint::Min(16),
+            Constraint::Length(20),
+            Constraint::Length(10),
+            Constraint::Length(20),
+            Constraint::Length(10),
+        ];
+        let header = Row::new(["Day", "Title", "Part 1", "Time", "Part 2", "Time"]).bold();
+        let table = Table::new(rows, widths)
+            .header(header)
+            .row_highlight_style(Style::new().reversed())
+            .block(Block::bordered().title(" Advent of Code 2024 "));
+        screen.render_stateful_widget(table, list, &mut self.table);
+
+        let entry = &self.entries[self.selected()];
+        let input = match &self.mode {
+            Mode::EditInput(input) => {
+                // After the border and `Input: `.
+                let column = details.x + 8 + input.chars().count() as u16;
+                screen.set_cursor_position((column.min(details.right() - 2), details.y + 1));
+                input.clone()
+            }
+            _ => entry.input.display().to_string(),
+        };
+        let mut lines = vec![Line::from(format!("Input: {input}"))];
+        lines.push(Line::from(match entry.day.visualize {
+            Some(_) => "Visualization: press v to play it",
+            None => "Visualization: none",
+        }));
+        if let Some(parse) = entry.parse {
+            lines.push(Line::from(format!("Parsing took {parse:.2?}")));
+        }
+        for (part, answer) in entry.answers.iter().enumerate() {
+            if let Answer::Failed(error) = answer {
+                lines.push(Line::from(format!("Part {}: {error}", part + 1)).red());
+            }
+        }
+        let title = format!(" Day {}: {} ", entry.day.number, entry.title);
+        let details_block = Paragraph::new(lines).block(Block::bordered().title(title));
+        screen.render_widget(details_block, details);
+
+        let footer_text = match &self.mode {
+            Mode::EditInput(_) => Line::from("enter use this input  esc cancel").dim(),
+            _ if !self.status.is_empty() => Line::from(self.status.as_str()).yellow(),
+            _ => Line::from(HELP).dim(),
+        };
+        screen.render_widget(footer_text, footer);
+    }
+}
+
+/// The indices of `part`, or of both parts when `None`.
+fn parts(part: Option<u8>) -> Vec<usize> {
+    match part {
+        Some(part) => vec![usize::from(part - 1)],
+        None => vec![0, 1],
+    }
+}
+
+/// The answer and time columns of the list for `answer`.
+fn answer_cells(answer: &Answer) -> [Cell<'_>; 2] {
+    match answer {
+        Answer::Unknown => [Cell::from(""), Cell::from("")],
+        Answer::Running => [Cell::from("running…").yellow(), Cell::from("")],
+        Answer::Cached(answer) => [Cell::from(answer.as_str()), Cell::from("cached").dim()],
+        Answer::Solved { answer, time } => [
+            Cell::from(answer.as_str()),
+            Cell::from(format!("{time:.2?}")),
+        ],
+        Answer::Failed(_) => [Cell::from("error").red(), Cell::from("")],
+    }
+}
+
+/// What the visualization thread sends the viewer.
+enum Shown {
+    Frame(Frame),
+    Finished(Result<(), String>),
+}
+
+/// Passes frames on to the viewer, failing once it is closed so the visualization stops.
+struct Channel(SyncSender<Shown>);
+
+impl Sink for Channel {
+    fn frame(&mut self, frame: &Frame) -> io::Result<()> {
+        self.0
+            .send(Shown::Frame(frame.clone()))
+            .map_err(|_| io::Error::new(io::ErrorKind::BrokenPipe, "the viewer was closed"))
+    }
+}
+
+/// A visualization playing in the dashboard.
+struct Viewer {
+    title: String,
+    frames: Receiver<Shown>,
+    frame: Option<Frame>,
+    /// Frames received so far.
+    count: usize,
+    finished: Option<Result<(), String>>,
+    paused: bool,
+    /// Frames moved ahead by every tick.
+    speed: usize,
+    /// The first row and column of the frame on screen.
+    offset: (usize, usize),
+}
+
+impl Viewer {
+    fn new(title: String, frames: Receiver<Shown>) -> Viewer {
+        Viewer {
+            title,
+            frames,
+            frame: None,
+            count: 0,
+            finished: None,
+            paused: false,
+            speed: 1,
+            offset: (0, 0),
+        }
+    }
+
+    /// Moves the animation ahead by `speed` frames, as far as they have been sent.
+    fn advance(&mut self) {
+        if self.paused {
+            return;
+        }
+
+        for _ in 0..self.speed {
+            match self.frames.try_recv() {
+                Ok(Shown::Frame(frame)) => {
+                    self.frame = Some(frame);
+                    self.count += 1;
+                }
+                Ok(Shown::Finished(outcome)) => self.finished = Some(outcome),
+                Err(_) => break,
+            }
+        }
+    }
+
+    /// Scrolls by `rows` and `cols`, keeping part of the frame on screen.
+    fn scroll(&mut self, rows: isize, cols: isize) {
+        let Some(frame) = &self.frame else {
+            return;
+        };
+        let (row, col) = self.offset;
+        let last_row = frame.cells.height().saturating_sub(1);
+        let last_col = frame.cells.width().saturating_sub(1);
+        self.offset = (
+            row.saturating_add_signed(rows).min(last_row),
+            col.saturating_add_signed(cols).min(last_col),
+        );
+    }
+
+    fn draw(&self, screen: &mut ratatui::Frame) {
+        let state = match &self.finished {
+            None if self.paused => String::from("paused"),
+            None => format!("{}× speed", self.speed),
+            Some(Ok(())) => String::from("finished"),
+            Some(Err(_)) => String::from("failed"),
+        };
+        let title = format!(" {}: frame {}, {state} ", self.title, self.count);
+        let block = Block::bordered().title(title);
+        let inner = block.inner(screen.area());
+        screen.render_widget(block, screen.area());
+
+        let [picture, caption, help] = Layout::vertical([
+            Constraint::Min(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .areas(inner);
+        if let Some(frame) = &self.frame {
+            let view = FrameView {
+                frame,
+                offset: self.offset,
+            };
+            screen.render_widget(view, picture);
+            screen.render_widget(Line::from(frame.caption.as_str()), caption);
+        }
+        if let Some(Err(error)) = &self.finished {
+            screen.render_widget(Line::from(error.as_str()).red(), caption);
+        }
+        screen.render_widget(Line::from(VIEWER_HELP).dim(), help);
+    }
+}
+
+/// Draws the cells of a frame from the row and column `offset` on: a character per cell when
+/// the rows fit, or else two rows per line as half blocks, leaving the characters out.
+struct FrameView<'a> {
+    frame: &'a Frame,
+    offset: (usize, usize),
+}
+
+impl Widget for FrameView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let cells = &self.frame.cells;
+        let halves = cells.height() > usize::from(area.height);
+        let rows_per_line = if halves { 2 } else { 1 };
+        let (top, left) = self.offset;
+
+        for y in 0..area.height {
+            let row = top + usize::from(y) * rows_per_line;
+            if row >= cells.height() {
+                break;
+            }
+            let below = (halves && row + 1 < cells.height()).then(|| cells.row(row + 1));
+
+            for (x, cell) in (0..area.width).zip(cells.row(row).iter().skip(left)) {
+                let target = &mut buf[(area.x + x, area.y + y)];
+                if halves {
+                    let bottom =
+                        below.map_or(Color::Reset, |below| color(below[left + usize::from(x)].bg));
+                    target.set_char('▀').set_fg(color(cell.bg)).set_bg(bottom);
+                } else {
+                    target
+                        .set_char(cell.glyph)
+                        .set_fg(color(cell.fg))
+                        .set_bg(color(cell.bg));
+                }
+            }
+        }
+    }
+}
+
+fn color(Rgb(r, g, b): Rgb) -> Color {
+    Color::Rgb(r, g, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::time::Instant;
+
+    use aoc_grid::Grid;
+    use aoc_viz::Cell as VizCell;
+
+    use super::*;
+    use crate::days;
+
+    fn press(app: &mut App, code: KeyCode) {
+        app.handle_key(KeyEvent::from(code));
+    }
+
+    fn open(name: &str) -> (App, PathBuf) {
+        let dir = env::temp_dir().join(format!("aoc-dashboard-{name}-{}", std::process::id()));
+        let days = days::DAYS
+            .iter()
+            .map(|day| (day, dir.join(format!("day{:02}.txt", day.number))))
+            .collect();
+        let cache = AnswerCache::new(dir.join("answers"));
+        (App::new(days, Path::new(""), cache), dir)
+    }
+
+    #[test]
+    fn test_readme_title() {
+        assert_eq!(
+            readme_title("# Day 6: Guard Gallivant\n\nThe Historians"),
+            Some("Guard Gallivant")
+        );
+        assert_eq!(readme_title("Day 6\n"), None);
+        assert_eq!(readme_title(""), None);
+    }
+
+    #[test]
+    fn test_keys() {
+        let (mut app, _) = open("keys");
+        press(&mut app, KeyCode::Up);
+        assert_eq!(app.selected(), 0);
+        let last = days::DAYS.len() - 1;
+        for _ in 0..=last + 2 {
+            press(&mut app, KeyCode::Down);
+        }
+        assert_eq!(app.selected(), last);
+
+        press(&mut app, KeyCode::Char('i'));
+        let Mode::EditInput(input) = &app.mode else {
+            panic!("i edits the input");
+        };
+        for _ in 0..input.len() {
+            press(&mut app, KeyCode::Backspace);
+        }
+        for c in "other.txt".chars() {
+            press(&mut app, KeyCode::Char(c));
+        }
+        press(&mut app, KeyCode::Enter);
+        assert!(matches!(app.mode, Mode::Browse));
+        assert_eq!(app.entries[last].input, PathBuf::from("other.txt"));
+
+        for _ in 0..=last {
+            press(&mut app, KeyCode::Up);
+        }
+        press(&mut app, KeyCode::Char('v'));
+        assert_eq!(app.status, "Day 1 has no visualization");
+        press(&mut app, KeyCode::Char('q'));
+        assert!(app.quit);
+    }
+
+    #[test]
+    fn test_solve() {
+        let (mut app, dir) = open("solve");
+        fs::create_dir_all(&dir).unwrap();
+        let input = aoc_gen::generate(1, 25, 0).unwrap();
+        fs::write(dir.join("day01.txt"), &input).unwrap();
+
+        let solved = |app: &mut App| {
+            let start = Instant::now();
+            while app.entries[0].answers.contains(&Answer::Running) {
+                assert!(
+                    start.elapsed() < Duration::from_secs(10),
+                    "day 1 is not solved"
+                );
+                thread::sleep(Duration::from_millis(1));
+                app.receive();
+            }
+        };
+
+        press(&mut app, KeyCode::Char('2'));
+        assert_eq!(app.entries[0].answers[1], Answer::Running);
+        solved(&mut app);
+        let expected = days::DAYS[0].solve_part;
+        let part_2 = expected(&input, 2).unwrap().answer;
+        assert_eq!(app.entries[0].answers[0], Answer::Unknown);
+        assert!(
+            matches!(&app.entries[0].answers[1], Answer::Solved { answer, .. } if *answer == part_2)
+        );
+
+        press(&mut app, KeyCode::Char('r'));
+        solved(&mut app);
+        // Solving both parts caches the answers for the next start.
+        let (restarted, _) = open("solve");
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(restarted.entries[0].answers[1], Answer::Cached(part_2));
+    }
+
+    #[test]
+    fn test_frame_view() {
+        let grid = Grid::parse("ab\ncd\nef", |_, c| Ok(c)).unwrap();
+        let frame = Frame::render(&grid, |_, &c| {
+            VizCell::new(c, Rgb::WHITE, Rgb(c as u8, 0, 0))
+        });
+        let render = |width, height, offset| {
+            let mut buf = Buffer::empty(Rect::new(0, 0, width, height));
+            FrameView {
+                frame: &frame,
+                offset,
+            }
+            .render(buf.area, &mut buf);
+            buf
+        };
+
+        let full = render(2, 3, (0, 0));
+        assert_eq!(full[(1, 2)].symbol(), "f");
+        assert_eq!(full[(1, 2)].bg, Color::Rgb(b'f', 0, 0));
+
+        let scrolled = render(2, 3, (1, 1));
+        assert_eq!(scrolled[(0, 0)].symbol(), "d");
+        assert_eq!(scrolled[(1, 0)].symbol(), " ");
+
+        let halves = render(2, 2, (0, 0));
+        assert_eq!(halves[(0, 0)].symbol(), "▀");
+        assert_eq!(
+            (halves[(0, 0)].fg, halves[(0, 0)].bg),
+            (Color::Rgb(b'a', 0, 0), Color::Rgb(b'c', 0, 0))
+        );
+        assert_eq!(halves[(1, 1)].bg, Color::Reset);
+        assert_eq!(halves[(1, 1)].fg, Color::Rgb(b'f', 0, 0));
+    }
+}
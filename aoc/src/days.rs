@@ -0,0 +1,105 @@
+use aoc_core::{Answers, Error, PartAnswer, Solution};
+use aoc_viz::Sink;
+
+/// Animates how a day is solved for an input, into a sink.
+pub type Visualize = fn(&str, &mut dyn Sink) -> Result<(), Error>;
+
+/// A solved day the runner can dispatch to.
+pub struct Day {
+    pub number: u8,
+    /// The options of its own the day reads from `aoc.toml`.
+    pub options: &'static [&'static str],
+    pub solve: fn(&str) -> Result<Answers, Error>,
+    pub solve_part: fn(&str, u8) -> Result<PartAnswer, Error>,
+    pub visualize: Option<Visualize>,
+}
+
+pub const DAYS: &[Day] = &[
+    day::<day01::Day01>(),
+    day::<day02::Day02>(),
+    day::<day03::Day03>(),
+    day::<day04::Day04>().visualized(visualize_day04),
+    day::<day05::Day05>(),
+    day::<day06::Day06>().visualized(visualize_day06),
+    day::<day07::Day07>(),
+];
+
+const fn day<S: Solution>() -> Day {
+    Day {
+        number: S::DAY,
+        options: S::OPTIONS,
+        solve: aoc_core::solve::<S>,
+        solve_part: aoc_core::solve_part::<S>,
+        visualize: None,
+    }
+}
+
+impl Day {
+    const fn visualized(self, visualize: Visualize) -> Day {
+        Day {
+            visualize: Some(visualize),
+            ..self
+        }
+    }
+}
+
+fn visualize_day04(input: &str, sink: &mut dyn Sink) -> Result<(), Error> {
+    let puzzle = day04::Day04::parse(input)?;
+    day04::viz::matches(&puzzle, "XMAS", sink)?;
+    day04::viz::crosses(&puzzle, "MAS", sink)
+}
+
+fn visualize_day06(input: &str, sink: &mut dyn Sink) -> Result<(), Error> {
+    let board = day06::Day06::parse(input)?;
+    day06::viz::walk(board.clone(), sink)?;
+    day06::viz::obstructions(board, sink)
+}
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use aoc_core::input::expand_inputs;
+    use aoc_core::read_input;
+
+    use super::*;
+
+    #[test]
+    fn test_generated_inputs_solve() {
+        for day in DAYS {
+            for seed in 0..3 {
+                // Days fresh from `aoc new` have no generator yet.
+                let Some(input) = aoc_gen::generate(day.number, 25, seed) else {
+                    continue;
+                };
+                let outcome = (day.solve)(&input);
+                assert!(
+                    outcome.is_ok(),
+                    "day {} seed {seed}: {outcome:?}",
+                    day.number
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_day_directory_as_input() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+        for day in DAYS {
+            let dir = root.join(format!("day{:02}", day.number));
+            // Days fresh from `aoc new` have no input yet.
+            if !dir.join("input.txt").is_file() {
+                continue;
+            }
+
+            let inputs = expand_inputs(std::slice::from_ref(&dir)).unwrap();
+            assert_eq!(inputs, [dir.join("input.txt")], "day {}", day.number);
+            let outcome = (day.solve)(&read_input(&inputs[0]).unwrap());
+            assert!(outcome.is_ok(), "day {}: {outcome:?}", day.number);
+        }
+    }
+}
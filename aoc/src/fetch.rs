@@ -0,0 +1,137 @@
+//! `aoc fetch`: downloads the puzzle input of a day into `dayNN/input.txt`.
+//!
+//! Inputs never change, so every download is kept in the cache directory as well. An input that
+//! is already in the day's folder or in the cache is not downloaded again.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::site::{Backend, RateLimiter, CACHE_DIR, YEAR};
+
+/// Where the input came from.
+#[derive(Debug, PartialEq)]
+pub enum Fetched {
+    /// The day's folder already had it.
+    Present(PathBuf),
+    /// Copied from the cache.
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Puts the input of `day` into its folder below the workspace `root`, downloading it through
+/// `backend` when it is not cached.
+pub fn fetch(
+    root: &Path,
+    day: u8,
+    backend: &dyn Backend,
+    limiter: &RateLimiter,
+) -> Result<Fetched, String> {
+    let day_dir = root.join(format!("day{day:02}"));
+    if !day_dir.is_dir() {
+        return Err(format!(
+            "{} does not exist, create it with `aoc new {day}` first",
+            day_dir.display()
+        ));
+    }
+
+    let input = day_dir.join("input.txt");
+    if fs::metadata(&input).is_ok_and(|m| m.len() > 0) {
+        return Ok(Fetched::Present(input));
+    }
+
+    let cached = cache_path(root, day);
+    if let Ok(text) = fs::read_to_string(&cached) {
+        write(&input, &text)?;
+        return Ok(Fetched::Cached(input));
+    }
+
+    limiter.wait()?;
+    let response = backend.get(&format!("/{YEAR}/day/{day}/input"))?;
+    let text = match response.status {
+        200 => response.body,
+        400 => return Err(String::from("the website rejected the session token")),
+        404 => return Err(format!("day {day} has not unlocked yet")),
+        status => {
+            let reason = response.body.lines().next().unwrap_or_default();
+            return Err(format!("the website answered {status}: {reason}"));
+        }
+    };
+
+    write(&cached, &text)?;
+    write(&input, &text)?;
+    Ok(Fetched::Downloaded(input))
+}
+
+fn cache_path(root: &Path, day: u8) -> PathBuf {
+    root.join(CACHE_DIR)
+        .join(YEAR.to_string())
+        .join(format!("day{day:02}.txt"))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("unable to create {}: {e}", parent.display()))?;
+    }
+    fs::write(path, contents).map_err(|e| format!("unable to write {}: {e}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::site::stub::StubServer;
+    use crate::site::HttpBackend;
+
+    fn workspace(name: &str) -> (PathBuf, RateLimiter) {
+        let root = std::env::temp_dir().join(format!("aoc-fetch-{name}-{}", std::process::id()));
+        fs::create_dir_all(root.join("day01")).unwrap();
+        let limiter = RateLimiter::new(root.join(CACHE_DIR).join("last"), Duration::ZERO);
+        (root, limiter)
+    }
+
+    #[test]
+    fn test_fetch_downloads_once() {
+        let (root, limiter) = workspace("once");
+        let server = StubServer::start(&[(200, "3   4\n4   3\n")]);
+        let backend = HttpBackend::new(&server.base_url, "abc");
+
+        let first = fetch(&root, 1, &backend, &limiter);
+        let input = fs::read_to_string(root.join("day01/input.txt"));
+        let second = fetch(&root, 1, &backend, &limiter);
+        fs::remove_file(root.join("day01/input.txt")).unwrap();
+        let third = fetch(&root, 1, &backend, &limiter);
+        let requests = server.finish();
+        fs::remove_dir_all(&root).unwrap();
+
+        let path = root.join("day01/input.txt");
+        assert_eq!(first, Ok(Fetched::Downloaded(path.clone())));
+        assert_eq!(input.unwrap(), "3   4\n4   3\n");
+        assert_eq!(second, Ok(Fetched::Present(path.clone())));
+        assert_eq!(third, Ok(Fetched::Cached(path)));
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2024/day/1/input "));
+    }
+
+    #[test]
+    fn test_fetch_errors() {
+        let (root, limiter) = workspace("errors");
+        let server = StubServer::start(&[(400, "Please log in"), (404, "Not yet"), (500, "Oops")]);
+        let backend = HttpBackend::new(&server.base_url, "abc");
+
+        let rejected = fetch(&root, 1, &backend, &limiter);
+        let locked = fetch(&root, 1, &backend, &limiter);
+        let failed = fetch(&root, 1, &backend, &limiter);
+        let missing_day = fetch(&root, 2, &backend, &limiter);
+        let written = root.join("day01/input.txt").exists();
+        server.finish();
+        fs::remove_dir_all(&root).unwrap();
+
+        assert!(rejected.unwrap_err().contains("session token"));
+        assert_eq!(locked, Err(String::from("day 1 has not unlocked yet")));
+        assert!(failed.unwrap_err().contains("500: Oops"));
+        assert!(missing_day.unwrap_err().contains("aoc new 2"));
+        assert!(!written);
+    }
+}
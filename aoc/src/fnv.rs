@@ -0,0 +1,52 @@
+//! FNV-1a, a hash that stays the same across Rust releases, unlike `DefaultHasher`. The build
+//! script includes this file too, so that the keys of the answer cache only change when what
+//! they are made of does.
+
+const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// 64-bit FNV-1a hash of a sequence of fields.
+#[derive(Debug, Clone)]
+pub struct Fnv(u64);
+
+impl Default for Fnv {
+    fn default() -> Fnv {
+        Fnv(OFFSET_BASIS)
+    }
+}
+
+impl Fnv {
+    /// Adds `bytes`, prefixed with their length so that neighbouring fields cannot run into each
+    /// other.
+    pub fn field(&mut self, bytes: &[u8]) -> &mut Fnv {
+        let length = (bytes.len() as u64).to_le_bytes();
+        for &byte in length.iter().chain(bytes) {
+            self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(PRIME);
+        }
+        self
+    }
+
+    pub fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fields() {
+        // Changing these values changes every cache key and source hash.
+        assert_eq!(Fnv::default().finish(), OFFSET_BASIS);
+        assert_eq!(Fnv::default().field(b"").finish(), 0xa8c7_f832_281a_39c5);
+        assert_eq!(
+            Fnv::default().field(b"day").field(b"1 2").finish(),
+            0xd68d_f450_45ba_616c
+        );
+        assert_ne!(
+            Fnv::default().field(b"ab").field(b"c").finish(),
+            Fnv::default().field(b"a").field(b"bc").finish()
+        );
+    }
+}
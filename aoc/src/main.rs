@@ -0,0 +1,668 @@
+use std::ffi::OsString;
+use std::fs;
+use std::io::{self, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::str::FromStr;
+use std::time::Instant;
+
+use aoc_core::alloc::CountingAllocator;
+use aoc_core::cli::print_timings;
+use aoc_core::config::Config;
+use aoc_core::input::{expand_inputs, STDIN};
+use aoc_core::read_input;
+use aoc_core::report::{Format, ReportWriter};
+use aoc_core::timings::measure;
+use cache::AnswerCache;
+use clap::{Parser, Subcommand, ValueEnum};
+use days::Day;
+use fetch::Fetched;
+use site::{HttpBackend, RateLimiter};
+use submit::{History, Outcome, Submitted};
+use summary::Row;
+
+mod cache;
+mod dashboard;
+mod days;
+mod fetch;
+mod fnv;
+mod pool;
+mod scaffold;
+mod site;
+mod submit;
+mod summary;
+mod verify;
+mod watch;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[derive(Parser, Debug)]
+#[command(name = "aoc", about = "Runs the Advent of Code 2024 solutions")]
+struct Args {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Solve both parts of one day, or of every day with `all`
+    Run {
+        /// Day number (1-25) or `all`
+        day: DaySelection,
+        /// Puzzle inputs: files, directories of `*.txt` files, or `-` for stdin. Defaults to the
+        /// `input` of the day in `aoc.toml`, or `dayNN/input.txt`
+        #[arg(
+            short,
+            long = "input-file",
+            num_args = 1..,
+            value_hint(clap::ValueHint::AnyPath)
+        )]
+        input_files: Vec<PathBuf>,
+        /// Output format, `text` prints a table. Defaults to the `format` in `aoc.toml`, or
+        /// `text`
+        #[arg(short, long, value_enum)]
+        format: Option<Format>,
+        /// Report wall time and peak heap usage of every phase on stderr
+        #[arg(long)]
+        timings: bool,
+        /// Number of inputs to solve at the same time. Defaults to one per CPU. Peak heap usage
+        /// is only reported with `--jobs 1`, as it cannot be told apart between threads
+        #[arg(short, long, value_parser = clap::value_parser!(u16).range(1..))]
+        jobs: Option<u16>,
+        /// Keep running, and solve again whenever one of the inputs changes
+        #[arg(long)]
+        watch: bool,
+        /// With --watch, also watch `dayNN/src` and rebuild the runner when it changes
+        #[arg(long, requires = "watch")]
+        watch_sources: bool,
+        /// Solve every input, instead of reusing the answers of an earlier run with the same
+        /// input and solver
+        #[arg(long)]
+        no_cache: bool,
+    },
+    /// Check the answers for the checked-in inputs against `dayNN/.answers`
+    Verify {
+        /// Day number (1-25) or `all`
+        #[arg(default_value = "all")]
+        day: DaySelection,
+    },
+    /// Create the `dayNN` crate from the templates and register it with the workspace and runner
+    New {
+        /// Day number (1-25)
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Download the puzzle input of a day from adventofcode.com into `dayNN/input.txt`
+    Fetch {
+        /// Day number (1-25)
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Solve a day and submit the answer of one part to adventofcode.com
+    Submit {
+        /// Day number (1-25)
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Part to submit (1-2)
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Puzzle input to solve. Defaults to the `input` of the day in `aoc.toml`, or
+        /// `dayNN/input.txt`
+        #[arg(short, long = "input-file", value_hint(clap::ValueHint::FilePath))]
+        input_file: Option<PathBuf>,
+    },
+    /// Browse the days in a terminal UI: see their last answers, solve a part, change the input
+    /// and play the visualizations
+    Dashboard,
+    /// Write a random puzzle input for a day to stdout or a file
+    Generate {
+        /// Day number (1-25)
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Size of the input, what it counts depends on the day. Defaults to the size of the real
+        /// puzzle input
+        #[arg(long)]
+        size: Option<usize>,
+        /// Seed for the random number generator, the same seed gives the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// File to write the input to instead of stdout
+        #[arg(short, long, value_hint(clap::ValueHint::FilePath))]
+        output: Option<PathBuf>,
+    },
+}
+
+#[derive(Clone, Copy, Debug)]
+enum DaySelection {
+    Day(u8),
+    All,
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("all") {
+            return Ok(DaySelection::All);
+        }
+
+        match s.parse::<u8>() {
+            Ok(day) if days::find(day).is_some() => Ok(DaySelection::Day(day)),
+            Ok(day) => Err(format!("Day {day} has not been solved yet")),
+            Err(_) => Err(format!("Expected a day number or `all`, got {s}")),
+        }
+    }
+}
+
+impl DaySelection {
+    fn to_arg(self) -> String {
+        match self {
+            DaySelection::Day(day) => day.to_string(),
+            DaySelection::All => String::from("all"),
+        }
+    }
+}
+
+/// How `aoc run` solves and reports.
+#[derive(Clone, Copy, Debug)]
+struct RunOptions {
+    format: Format,
+    timings: bool,
+    jobs: usize,
+    cache: bool,
+}
+
+/// `dayNN/input.txt` in the workspace `root`.
+fn default_input(root: &Path, day: u8) -> PathBuf {
+    root.join(format!("day{day:02}/input.txt"))
+}
+
+/// Solves `day` for the input in `input_file`, or looks the answers up in `cache` if given.
+fn run_day(day: &Day, input_file: &Path, cache: Option<&AnswerCache>) -> Row {
+    let (input, read) = measure(|| read_input(input_file));
+    let mut row = Row {
+        day: day.number,
+        input: input_file.to_path_buf(),
+        read,
+        outcome: Err(String::new()),
+        cached: false,
+    };
+    let input = match input {
+        Ok(input) => input,
+        Err(error) => {
+            row.outcome = Err(error.to_string());
+            return row;
+        }
+    };
+
+    if let Some(answers) = cache.and_then(|cache| cache.get(day.number, &input)) {
+        row.outcome = Ok(answers);
+        row.cached = true;
+        return row;
+    }
+
+    row.outcome = (day.solve)(&input).map_err(|error| error.to_string());
+    if let (Some(cache), Ok(answers)) = (cache, &row.outcome) {
+        if let Err(error) = cache.put(&input, answers) {
+            eprintln!("warning: unable to cache the answers: {error}");
+        }
+    }
+    row
+}
+
+/// Solves every day for its input on up to `jobs` threads, keeping the order of `runs`.
+fn run_days(runs: &[(&Day, PathBuf)], jobs: usize, cache: Option<&AnswerCache>) -> Vec<Row> {
+    let outcomes = pool::map(runs, jobs, |(day, input)| run_day(day, input, cache));
+
+    runs.iter()
+        .zip(outcomes)
+        .map(|((day, input), outcome)| {
+            let mut row = outcome.unwrap_or_else(|message| Row {
+                day: day.number,
+                input: input.clone(),
+                read: Default::default(),
+                outcome: Err(format!("solver panicked: {message}")),
+                cached: false,
+            });
+            if jobs > 1 && runs.len() > 1 {
+                row.forget_peak_heap();
+            }
+            row
+        })
+        .collect()
+}
+
+fn write_reports(rows: &[Row], format: Format) -> io::Result<()> {
+    let mut writer = ReportWriter::new(io::stdout().lock(), format);
+    for row in rows {
+        match &row.outcome {
+            Ok(answers) => writer.write(&row.input.display().to_string(), answers)?,
+            Err(error) => eprintln!("error: day {}: {}: {error}", row.day, row.input.display()),
+        }
+    }
+    Ok(())
+}
+
+fn selected_days(selection: DaySelection) -> Vec<&'static Day> {
+    match selection {
+        DaySelection::Day(number) => {
+            vec![days::find(number).expect("Day selection is validated by clap")]
+        }
+        DaySelection::All => days::DAYS.iter().collect(),
+    }
+}
+
+/// The inputs `aoc run` reads for every selected day, before directories are expanded. Fails for
+/// a day with an option in `aoc.toml` that it does not read.
+fn run_inputs(
+    day: DaySelection,
+    input_files: &[PathBuf],
+    config: &Config,
+) -> Result<Vec<(&'static Day, Vec<PathBuf>)>, String> {
+    if matches!(day, DaySelection::All) && !input_files.is_empty() {
+        return Err(String::from("--input-file cannot be combined with `all`"));
+    }
+
+    selected_days(day)
+        .into_iter()
+        .map(|day| {
+            config.check_options(day.number, day.options)?;
+            let inputs = if !input_files.is_empty() {
+                input_files.to_vec()
+            } else {
+                let inputs = config.inputs(day.number);
+                if inputs.is_empty() {
+                    vec![default_input(config.dir(), day.number)]
+                } else {
+                    inputs
+                }
+            };
+            Ok((day, inputs))
+        })
+        .collect()
+}
+
+fn run(
+    day: DaySelection,
+    input_files: &[PathBuf],
+    config: &Config,
+    options: RunOptions,
+) -> ExitCode {
+    let inputs = match run_inputs(day, input_files, config) {
+        Ok(inputs) => inputs,
+        Err(error) => {
+            eprintln!("error: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut runs: Vec<(&Day, PathBuf)> = vec![];
+    for (day, inputs) in inputs {
+        match expand_inputs(&inputs) {
+            Ok(inputs) => runs.extend(inputs.into_iter().map(|input| (day, input))),
+            Err(error) => {
+                eprintln!("error: {error}");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    let cache = options
+        .cache
+        .then(|| AnswerCache::in_workspace(config.dir()));
+    let start = Instant::now();
+    let rows = run_days(&runs, options.jobs, cache.as_ref());
+    let wall_time = start.elapsed();
+
+    let written = if options.format == Format::Text {
+        let color = io::stdout().is_terminal();
+        summary::write_table(io::stdout().lock(), &rows, wall_time, color)
+    } else {
+        write_reports(&rows, options.format)
+    };
+    if let Err(error) = written {
+        eprintln!("error: unable to write output: {error}");
+        return ExitCode::FAILURE;
+    }
+
+    if options.timings {
+        for row in rows.iter().filter(|row| !row.cached) {
+            if let Ok(answers) = &row.outcome {
+                eprintln!("\nDay {} ({})", row.day, row.input.display());
+                if let Err(error) = print_timings(row.read, &answers.timings) {
+                    eprintln!("error: unable to write output: {error}");
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+    }
+
+    if rows.iter().any(|row| row.outcome.is_err()) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn run_watched(
+    day: DaySelection,
+    input_files: &[PathBuf],
+    config: &Config,
+    options: RunOptions,
+    watch_sources: bool,
+) -> ExitCode {
+    let inputs: Vec<PathBuf> = match run_inputs(day, input_files, config) {
+        Ok(inputs) => inputs.into_iter().flat_map(|(_, inputs)| inputs).collect(),
+        Err(error) => {
+            eprintln!("error: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    if inputs
+        .iter()
+        .any(|input| input.as_path() == Path::new(STDIN))
+    {
+        eprintln!("error: --watch cannot read the input from stdin");
+        return ExitCode::FAILURE;
+    }
+
+    let sources: Vec<PathBuf> = if watch_sources {
+        selected_days(day)
+            .into_iter()
+            .flat_map(|day| watch::day_sources(config.dir(), day.number))
+            .collect()
+    } else {
+        vec![]
+    };
+
+    let mut run_args: Vec<OsString> = vec!["run".into(), day.to_arg().into()];
+    if !input_files.is_empty() {
+        run_args.push("--input-file".into());
+        run_args.extend(input_files.iter().map(OsString::from));
+    }
+    let format = options.format.to_possible_value();
+    let format = format.expect("Formats are not skipped");
+    run_args.extend(["--format".into(), format.get_name().into()]);
+    run_args.extend(["--jobs".into(), options.jobs.to_string().into()]);
+    if options.timings {
+        run_args.push("--timings".into());
+    }
+    if !options.cache {
+        run_args.push("--no-cache".into());
+    }
+
+    watch::watch(&inputs, &sources, &run_args, || {
+        run(day, input_files, config, options);
+    })
+}
+
+fn verify(day: DaySelection, root: &Path) -> ExitCode {
+    let mut failed = false;
+
+    println!("{:<5} {:<40} {:<40}", "Day", "Part 1", "Part 2");
+    for day in selected_days(day) {
+        let row = run_day(day, &default_input(root, day.number), None);
+        let outcome = row.outcome.and_then(|answers| {
+            verify::read_expected(&verify::answers_path(root, day.number))
+                .map(|expected| verify::compare(&expected, &answers))
+        });
+
+        match outcome {
+            Ok([part_1, part_2]) => {
+                failed |= part_1.is_failure() || part_2.is_failure();
+                println!(
+                    "{:<5} {:<40} {:<40}",
+                    day.number,
+                    part_1.to_string(),
+                    part_2.to_string()
+                );
+            }
+            Err(error) => {
+                failed = true;
+                println!("{:<5} error: {error}", day.number);
+            }
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn new_day(day: u8, root: &Path) -> ExitCode {
+    match scaffold::new_day(root, day) {
+        Ok(files) => {
+            for file in files {
+                println!("{}", file.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn fetch(day: u8, config: &Config) -> ExitCode {
+    let root = config.dir();
+    let fetched = HttpBackend::from_config(config.site(), root)
+        .and_then(|backend| fetch::fetch(root, day, &backend, &RateLimiter::for_site(root)));
+
+    match fetched {
+        Ok(Fetched::Present(path)) => println!("{} already exists", path.display()),
+        Ok(Fetched::Cached(path)) => println!("Copied the cached input to {}", path.display()),
+        Ok(Fetched::Downloaded(path)) => println!("Downloaded the input to {}", path.display()),
+        Err(error) => {
+            eprintln!("error: {error}");
+            return ExitCode::FAILURE;
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+/// The answer to `part` of `day` for its one input.
+fn submission_answer(
+    day: u8,
+    part: u8,
+    input_file: Option<PathBuf>,
+    config: &Config,
+) -> Result<String, String> {
+    let Some(day) = days::find(day) else {
+        return Err(format!("day {day} has not been solved yet"));
+    };
+    let input_files: Vec<PathBuf> = input_file.into_iter().collect();
+    let inputs = run_inputs(DaySelection::Day(day.number), &input_files, config)?;
+    let inputs = expand_inputs(&inputs[0].1).map_err(|e| e.to_string())?;
+    let [input] = inputs.as_slice() else {
+        return Err(format!(
+            "day {} has {} inputs, choose one with --input-file",
+            day.number,
+            inputs.len()
+        ));
+    };
+
+    let answers = run_day(day, input, None)
+        .outcome
+        .map_err(|e| format!("{}: {e}", input.display()))?;
+    Ok(if part == 1 {
+        answers.part_1
+    } else {
+        answers.part_2
+    })
+}
+
+fn submit(day: u8, part: u8, input_file: Option<PathBuf>, config: &Config) -> ExitCode {
+    let root = config.dir();
+    let submitted = submission_answer(day, part, input_file, config).and_then(|answer| {
+        println!("Day {day} part {part}: {answer}");
+        let mut history = History::load(History::path(root))?;
+        let backend = HttpBackend::from_config(config.site(), root)?;
+        let limiter = RateLimiter::for_site(root);
+        submit::submit(&mut history, day, part, &answer, &backend, &limiter)
+    });
+
+    let outcome = match submitted {
+        Ok(Submitted::Posted(outcome)) => outcome,
+        Ok(Submitted::Known(outcome)) => {
+            println!("Already submitted, not posting it again");
+            outcome
+        }
+        Err(error) => {
+            eprintln!("error: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    println!("{}", capitalize(&outcome.to_string()));
+    if outcome == Outcome::Correct {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    chars.next().map_or_else(String::new, |first| {
+        first.to_uppercase().chain(chars).collect()
+    })
+}
+
+fn dashboard(config: &Config) -> ExitCode {
+    let inputs = match run_inputs(DaySelection::All, &[], config) {
+        Ok(inputs) => inputs,
+        Err(error) => {
+            eprintln!("error: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let days = inputs
+        .into_iter()
+        .map(|(day, inputs)| {
+            let input = inputs.into_iter().next();
+            (
+                day,
+                input.unwrap_or_else(|| default_input(config.dir(), day.number)),
+            )
+        })
+        .collect();
+
+    let cache = AnswerCache::in_workspace(config.dir());
+    match dashboard::run(days, config.dir(), cache) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn generate(day: u8, size: Option<usize>, seed: u64, output: Option<PathBuf>) -> ExitCode {
+    let Some(generator) = aoc_gen::find(day) else {
+        eprintln!("error: there is no input generator for day {day}");
+        return ExitCode::FAILURE;
+    };
+
+    let size = size.unwrap_or(generator.default_size);
+    let input = aoc_gen::generate(day, size, seed).expect("Generator exists");
+    let written = match &output {
+        Some(path) => fs::write(path, input),
+        None => io::stdout().lock().write_all(input.as_bytes()),
+    };
+
+    match written {
+        Ok(()) => {
+            eprintln!("Day {day}: {size} {}, seed {seed}", generator.size_unit);
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("error: unable to write input: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    match args.command {
+        Command::Run {
+            day,
+            input_files,
+            format,
+            timings,
+            jobs,
+            watch,
+            watch_sources,
+            no_cache,
+        } => {
+            let config = match Config::find() {
+                Ok(config) => config,
+                Err(error) => {
+                    eprintln!("error: {error}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            let options = RunOptions {
+                format: format.or(config.format()).unwrap_or_default(),
+                timings,
+                jobs: jobs.map_or_else(pool::default_jobs, usize::from),
+                cache: !no_cache,
+            };
+            if watch {
+                run_watched(day, &input_files, &config, options, watch_sources)
+            } else {
+                run(day, &input_files, &config, options)
+            }
+        }
+        Command::Verify { day } => match Config::find() {
+            Ok(config) => verify(day, config.dir()),
+            Err(error) => {
+                eprintln!("error: {error}");
+                ExitCode::FAILURE
+            }
+        },
+        Command::New { day } => match Config::find() {
+            Ok(config) => new_day(day, config.dir()),
+            Err(error) => {
+                eprintln!("error: {error}");
+                ExitCode::FAILURE
+            }
+        },
+        Command::Fetch { day } => match Config::find() {
+            Ok(config) => fetch(day, &config),
+            Err(error) => {
+                eprintln!("error: {error}");
+                ExitCode::FAILURE
+            }
+        },
+        Command::Submit {
+            day,
+            part,
+            input_file,
+        } => match Config::find() {
+            Ok(config) => submit(day, part, input_file, &config),
+            Err(error) => {
+                eprintln!("error: {error}");
+                ExitCode::FAILURE
+            }
+        },
+        Command::Dashboard => match Config::find() {
+            Ok(config) => dashboard(&config),
+            Err(error) => {
+                eprintln!("error: {error}");
+                ExitCode::FAILURE
+            }
+        },
+        Command::Generate {
+            day,
+            size,
+            seed,
+            output,
+        } => generate(day, size, seed, output),
+    }
+}
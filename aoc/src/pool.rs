@@ -0,0 +1,89 @@
+//! A fixed number of worker threads that solve days or inputs concurrently.
+
+use std::any::Any;
+use std::num::NonZeroUsize;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+/// Number of workers to use when none is asked for: one per CPU.
+pub fn default_jobs() -> usize {
+    thread::available_parallelism().map_or(1, NonZeroUsize::get)
+}
+
+/// Calls `f` on every item on up to `jobs` threads, and returns the results in the order of
+/// `items`. A call that panics gives the panic message instead of a result, without affecting
+/// the other items.
+pub fn map<T: Sync, R: Send>(
+    items: &[T],
+    jobs: usize,
+    f: impl Fn(&T) -> R + Sync,
+) -> Vec<Result<R, String>> {
+    let next = AtomicUsize::new(0);
+    let results: Mutex<Vec<Option<Result<R, String>>>> =
+        Mutex::new((0..items.len()).map(|_| None).collect());
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(index) else {
+                    break;
+                };
+
+                let result = panic::catch_unwind(AssertUnwindSafe(|| f(item)));
+                let result = result.map_err(|payload| panic_message(payload.as_ref()));
+                results
+                    .lock()
+                    .expect("Workers never panic with the lock held")[index] = Some(result);
+            });
+        }
+    });
+
+    results
+        .into_inner()
+        .expect("Workers never panic with the lock held")
+        .into_iter()
+        .map(|result| result.expect("Every item is handled by a worker"))
+        .collect()
+}
+
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("unknown panic")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_keeps_order() {
+        let items: Vec<u64> = (0..50).collect();
+        for jobs in [0, 1, 4, 100] {
+            let results = map(&items, jobs, |n| n * n);
+            let squares: Vec<u64> = results.into_iter().map(Result::unwrap).collect();
+            assert_eq!(squares, items.iter().map(|n| n * n).collect::<Vec<_>>());
+        }
+
+        assert!(map(&[] as &[u8], 4, |_| ()).is_empty());
+    }
+
+    #[test]
+    fn test_map_isolates_panics() {
+        let results = map(&[1, 0, 2], 2, |&n| {
+            if n == 0 {
+                panic!("no zeroes");
+            }
+            10 / n
+        });
+
+        assert_eq!(results, vec![Ok(10), Err(String::from("no zeroes")), Ok(5)]);
+    }
+}
@@ -0,0 +1,203 @@
+//! `aoc new`: creates a day crate from the templates in `aoc/templates` and registers it in the
+//! workspace, the runner and `aoc.toml`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const TEMPLATES: [(&str, &str); 5] = [
+    ("Cargo.toml", include_str!("../templates/Cargo.toml.tmpl")),
+    ("src/main.rs", include_str!("../templates/main.rs.tmpl")),
+    ("src/lib.rs", include_str!("../templates/lib.rs.tmpl")),
+    ("readme.md", include_str!("../templates/readme.md.tmpl")),
+    (".answers", include_str!("../templates/answers.tmpl")),
+];
+
+/// Creates `dayNN` below the workspace `root` and registers it. Returns the files that were
+/// created or changed.
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("Day {day} is not part of the calendar"));
+    }
+
+    let name = format!("day{day:02}");
+    let crate_dir = root.join(&name);
+    if crate_dir.exists() {
+        return Err(format!("{} already exists", crate_dir.display()));
+    }
+
+    // Prepare every registration first, so a failure leaves the tree untouched.
+    let registrations = [
+        (root.join("Cargo.toml"), format!("    \"{name}\","), "\"day"),
+        (
+            root.join("aoc/Cargo.toml"),
+            format!("{name} = {{ path = \"../{name}\" }}"),
+            "day",
+        ),
+        (
+            root.join("aoc/src/days.rs"),
+            format!("    day::<{name}::Day{day:02}>(),"),
+            "day::<day",
+        ),
+    ];
+    let mut updates = vec![];
+    for (path, line, prefix) in registrations {
+        let text = fs::read_to_string(&path)
+            .map_err(|e| format!("unable to read {}: {e}", path.display()))?;
+        let text =
+            insert_entry(&text, &line, prefix).map_err(|e| format!("{}: {e}", path.display()))?;
+        updates.push((path, text));
+    }
+
+    let config = root.join(aoc_core::config::FILE_NAME);
+    if config.is_file() {
+        let text = fs::read_to_string(&config)
+            .map_err(|e| format!("unable to read {}: {e}", config.display()))?;
+        let text =
+            add_config_section(&text, day).map_err(|e| format!("{}: {e}", config.display()))?;
+        updates.push((config, text));
+    }
+
+    let mut changed = vec![];
+    for (file, template) in TEMPLATES {
+        let path = crate_dir.join(file);
+        write(&path, &render(template, day))?;
+        changed.push(path);
+    }
+    for (path, text) in updates {
+        write(&path, &text)?;
+        changed.push(path);
+    }
+
+    Ok(changed)
+}
+
+/// Fills in the `{{DAY}}` and `{{DAY_PADDED}}` placeholders.
+pub fn render(template: &str, day: u8) -> String {
+    template
+        .replace("{{DAY_PADDED}}", &format!("{day:02}"))
+        .replace("{{DAY}}", &day.to_string())
+}
+
+/// Inserts `line` into the block of lines starting with `prefix` (ignoring indentation), keeping
+/// the block sorted.
+pub fn insert_entry(text: &str, line: &str, prefix: &str) -> Result<String, String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let entries: Vec<usize> = (0..lines.len())
+        .filter(|&index| lines[index].trim_start().starts_with(prefix))
+        .collect();
+
+    let Some(&last) = entries.last() else {
+        return Err(format!("no existing `{prefix}` entries to add to"));
+    };
+    if entries.iter().any(|&index| lines[index] == line) {
+        return Err(format!("already contains `{}`", line.trim()));
+    }
+
+    let position = entries
+        .iter()
+        .copied()
+        .find(|&index| lines[index] > line)
+        .unwrap_or(last + 1);
+    lines.insert(position, line);
+
+    let mut text = lines.join("\n");
+    text.push('\n');
+    Ok(text)
+}
+
+/// Appends the `[dayNN]` table with the default input to the contents of `aoc.toml`.
+pub fn add_config_section(text: &str, day: u8) -> Result<String, String> {
+    let header = format!("[day{day:02}]");
+    if text.lines().any(|line| line.trim() == header) {
+        return Err(format!("already contains `{header}`"));
+    }
+
+    let mut text = text.trim_end().to_owned();
+    if !text.is_empty() {
+        text.push_str("\n\n");
+    }
+    text.push_str(&format!("{header}\ninput = \"day{day:02}/input.txt\"\n"));
+    Ok(text)
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("unable to create {}: {e}", parent.display()))?;
+    }
+    fs::write(path, contents).map_err(|e| format!("unable to write {}: {e}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        assert_eq!(
+            render("day{{DAY_PADDED}}::Day{{DAY_PADDED}} is day {{DAY}}", 8),
+            "day08::Day08 is day 8"
+        );
+    }
+
+    #[test]
+    fn test_insert_entry() {
+        let manifest = "members = [\n    \"aoc\",\n    \"day01\",\n    \"day03\",\n]\n";
+
+        assert_eq!(
+            insert_entry(manifest, "    \"day02\",", "\"day").unwrap(),
+            "members = [\n    \"aoc\",\n    \"day01\",\n    \"day02\",\n    \"day03\",\n]\n"
+        );
+        assert_eq!(
+            insert_entry(manifest, "    \"day08\",", "\"day").unwrap(),
+            "members = [\n    \"aoc\",\n    \"day01\",\n    \"day03\",\n    \"day08\",\n]\n"
+        );
+        assert!(insert_entry(manifest, "    \"day03\",", "\"day").is_err());
+        assert!(insert_entry(manifest, "day08 = {}", "day").is_err());
+    }
+
+    #[test]
+    fn test_add_config_section() {
+        assert_eq!(
+            add_config_section("format = \"text\"\n", 8).unwrap(),
+            "format = \"text\"\n\n[day08]\ninput = \"day08/input.txt\"\n"
+        );
+        assert_eq!(
+            add_config_section("", 8).unwrap(),
+            "[day08]\ninput = \"day08/input.txt\"\n"
+        );
+        assert!(add_config_section("[day08]\n", 8).is_err());
+    }
+
+    #[test]
+    fn test_new_day_registers_crate() {
+        let root = std::env::temp_dir().join(format!("aoc-new-{}", std::process::id()));
+        fs::create_dir_all(root.join("aoc/src")).unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"day01\",\n]\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc/Cargo.toml"),
+            "[dependencies]\nday01 = { path = \"../day01\" }\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc/src/days.rs"),
+            "pub const DAYS: &[Day] = &[\n    day::<day01::Day01>(),\n];\n",
+        )
+        .unwrap();
+
+        let result = new_day(&root, 8);
+        let days = fs::read_to_string(root.join("aoc/src/days.rs")).unwrap();
+        let lib = fs::read_to_string(root.join("day08/src/lib.rs")).unwrap();
+        let again = new_day(&root, 8);
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(result.unwrap().len(), TEMPLATES.len() + 3);
+        assert!(days.contains("    day::<day08::Day08>(),\n];"));
+        assert!(lib.contains("impl Solution for Day08"));
+        assert!(again.is_err());
+    }
+}
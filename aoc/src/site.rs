@@ -0,0 +1,339 @@
+//! Talking to the Advent of Code website: the session token, the HTTP backend and rate limiting.
+//!
+//! Everything goes through [`Backend`], so the commands can be tested against a stub server or
+//! a fake instead of the real website.
+
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use aoc_core::config::SiteConfig;
+use ureq::Agent;
+
+pub const YEAR: u16 = 2024;
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+/// Environment variable with the session cookie, which wins over the session file.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const DEFAULT_SESSION_FILE: &str = ".aoc-session";
+/// Directory for downloaded inputs and other state, relative to the workspace root.
+pub const CACHE_DIR: &str = ".aoc-cache";
+/// Least time between two requests to the website.
+pub const MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+const USER_AGENT: &str = "github.com/Miesvanderlippe/advent-of-rust-2024 aoc runner";
+
+/// The status and body of an HTTP response.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Sends requests to the website. `path` is relative to the base URL, like `/2024/day/1/input`.
+pub trait Backend {
+    fn get(&self, path: &str) -> Result<Response, String>;
+    fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<Response, String>;
+}
+
+/// [`Backend`] that talks HTTP, authenticated with a session cookie.
+pub struct HttpBackend {
+    agent: Agent,
+    base_url: String,
+    session: String,
+}
+
+impl HttpBackend {
+    pub fn new(base_url: &str, session: &str) -> HttpBackend {
+        let agent = Agent::config_builder()
+            .http_status_as_error(false)
+            .user_agent(USER_AGENT)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+
+        HttpBackend {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session: session.to_owned(),
+        }
+    }
+
+    /// Backend for the website configured in `config`, with the session token from the
+    /// environment or the session file.
+    pub fn from_config(config: &SiteConfig, root: &Path) -> Result<HttpBackend, String> {
+        let base_url = config.base_url.as_deref().unwrap_or(DEFAULT_BASE_URL);
+        Ok(HttpBackend::new(base_url, &session(config, root)?))
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+}
+
+impl Backend for HttpBackend {
+    fn get(&self, path: &str) -> Result<Response, String> {
+        let response = self
+            .agent
+            .get(format!("{}{path}", self.base_url))
+            .header("Cookie", self.cookie())
+            .call();
+        into_response(response)
+    }
+
+    fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<Response, String> {
+        let response = self
+            .agent
+            .post(format!("{}{path}", self.base_url))
+            .header("Cookie", self.cookie())
+            .send_form(form.iter().copied());
+        into_response(response)
+    }
+}
+
+fn into_response(
+    response: Result<ureq::http::Response<ureq::Body>, ureq::Error>,
+) -> Result<Response, String> {
+    let mut response = response.map_err(|e| format!("request failed: {e}"))?;
+    let body = response
+        .body_mut()
+        .read_to_string()
+        .map_err(|e| format!("unable to read the response: {e}"))?;
+
+    Ok(Response {
+        status: response.status().as_u16(),
+        body,
+    })
+}
+
+/// The session cookie from the [`SESSION_VAR`] environment variable, or else from the session
+/// file.
+pub fn session(config: &SiteConfig, root: &Path) -> Result<String, String> {
+    find_session(env::var(SESSION_VAR).ok(), config, root)
+}
+
+fn find_session(
+    from_env: Option<String>,
+    config: &SiteConfig,
+    root: &Path,
+) -> Result<String, String> {
+    if let Some(session) = from_env.filter(|s| !s.trim().is_empty()) {
+        return Ok(session.trim().to_owned());
+    }
+
+    let path = config
+        .session_file
+        .clone()
+        .unwrap_or_else(|| root.join(DEFAULT_SESSION_FILE));
+    match fs::read_to_string(&path) {
+        Ok(session) if !session.trim().is_empty() => Ok(session.trim().to_owned()),
+        Ok(_) => Err(format!("{} is empty", path.display())),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Err(format!(
+            "no session token, set {SESSION_VAR} or write it to {}",
+            path.display()
+        )),
+        Err(error) => Err(format!("unable to read {}: {error}", path.display())),
+    }
+}
+
+/// Keeps requests at least an interval apart, also between separate runs, by remembering the
+/// time of the last request in a file.
+pub struct RateLimiter {
+    state_file: PathBuf,
+    interval: Duration,
+}
+
+impl RateLimiter {
+    pub fn new(state_file: PathBuf, interval: Duration) -> RateLimiter {
+        RateLimiter {
+            state_file,
+            interval,
+        }
+    }
+
+    /// Limiter for the website, keeping its state in the cache directory below `root`.
+    pub fn for_site(root: &Path) -> RateLimiter {
+        RateLimiter::new(root.join(CACHE_DIR).join("last-request"), MIN_INTERVAL)
+    }
+
+    /// Sleeps until the interval since the last request has passed, and records a request as
+    /// made now. Returns how long it slept.
+    pub fn wait(&self) -> Result<Duration, String> {
+        let last = fs::read_to_string(&self.state_file)
+            .ok()
+            .and_then(|millis| millis.trim().parse().ok())
+            .map(|millis| UNIX_EPOCH + Duration::from_millis(millis));
+        let since = last.map_or(Ok(self.interval), |last| {
+            SystemTime::now().duration_since(last)
+        });
+
+        // A last request in the future means the clock changed, so do not trust it.
+        let delay = since.map_or(Duration::ZERO, |since| self.interval.saturating_sub(since));
+        if !delay.is_zero() {
+            eprintln!("Waiting {delay:.1?} before the next request to the website");
+            thread::sleep(delay);
+        }
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        if let Some(parent) = self.state_file.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("unable to create {}: {e}", parent.display()))?;
+        }
+        fs::write(&self.state_file, now.as_millis().to_string())
+            .map_err(|e| format!("unable to write {}: {e}", self.state_file.display()))?;
+
+        Ok(delay)
+    }
+}
+
+/// A local HTTP server with canned responses, standing in for the website in tests.
+#[cfg(test)]
+pub mod stub {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+    use std::thread::{self, JoinHandle};
+
+    pub struct StubServer {
+        pub base_url: String,
+        requests: Arc<Mutex<Vec<String>>>,
+        handle: Option<JoinHandle<()>>,
+    }
+
+    impl StubServer {
+        /// Answers one request per response, in order, and then stops listening.
+        pub fn start(responses: &[(u16, &str)]) -> StubServer {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let base_url = format!("http://{}", listener.local_addr().unwrap());
+            let requests = Arc::new(Mutex::new(vec![]));
+            let responses: Vec<(u16, String)> = responses
+                .iter()
+                .map(|(status, body)| (*status, body.to_string()))
+                .collect();
+
+            let recorded = Arc::clone(&requests);
+            let handle = thread::spawn(move || {
+                for (status, body) in responses {
+                    let (stream, _) = listener.accept().unwrap();
+                    let mut reader = BufReader::new(stream);
+                    let mut request = String::new();
+                    let mut content_length = 0;
+
+                    loop {
+                        let mut line = String::new();
+                        reader.read_line(&mut line).unwrap();
+                        if let Some((name, value)) = line.split_once(':') {
+                            if name.eq_ignore_ascii_case("content-length") {
+                                content_length = value.trim().parse().unwrap();
+                            }
+                        }
+                        request.push_str(&line);
+                        if line == "\r\n" || line.is_empty() {
+                            break;
+                        }
+                    }
+                    let mut content = vec![0; content_length];
+                    reader.read_exact(&mut content).unwrap();
+                    request.push_str(&String::from_utf8(content).unwrap());
+                    recorded.lock().unwrap().push(request);
+
+                    write!(
+                        reader.get_mut(),
+                        "HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                        body.len()
+                    )
+                    .unwrap();
+                }
+            });
+
+            StubServer {
+                base_url,
+                requests,
+                handle: Some(handle),
+            }
+        }
+
+        /// The raw requests received so far, headers and body.
+        pub fn requests(&self) -> Vec<String> {
+            self.requests.lock().unwrap().clone()
+        }
+
+        /// Waits until every response has been sent.
+        pub fn finish(mut self) -> Vec<String> {
+            self.handle.take().unwrap().join().unwrap();
+            self.requests()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::stub::StubServer;
+    use super::*;
+
+    #[test]
+    fn test_http_backend() {
+        let server = StubServer::start(&[(200, "input\n"), (404, "not yet")]);
+        let backend = HttpBackend::new(&format!("{}/", server.base_url), "abc");
+
+        let response = backend.get("/2024/day/1/input").unwrap();
+        assert_eq!(
+            response,
+            Response {
+                status: 200,
+                body: String::from("input\n")
+            }
+        );
+        let response = backend.post_form("/2024/day/1/answer", &[("level", "1")]);
+        assert_eq!(response.unwrap().status, 404);
+
+        let requests = server.finish();
+        assert!(requests[0].starts_with("GET /2024/day/1/input HTTP/1.1\r\n"));
+        assert!(requests[0]
+            .to_ascii_lowercase()
+            .contains("cookie: session=abc\r\n"));
+        assert!(requests[0].contains(USER_AGENT));
+        assert!(requests[1].starts_with("POST /2024/day/1/answer HTTP/1.1\r\n"));
+        assert!(requests[1].ends_with("\r\n\r\nlevel=1"));
+    }
+
+    #[test]
+    fn test_session_file() {
+        let dir = env::temp_dir().join(format!("aoc-session-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("session"), "abc\n").unwrap();
+        fs::write(dir.join("empty"), "\n").unwrap();
+        let config = |name: &str| SiteConfig {
+            session_file: Some(dir.join(name)),
+            ..SiteConfig::default()
+        };
+
+        let found = find_session(None, &config("session"), &dir);
+        let from_env = find_session(Some(String::from("def")), &config("session"), &dir);
+        let empty = find_session(Some(String::new()), &config("empty"), &dir);
+        let missing = find_session(None, &config("missing"), &dir);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(found, Ok(String::from("abc")));
+        assert_eq!(from_env, Ok(String::from("def")));
+        assert!(empty.is_err());
+        assert!(missing.unwrap_err().contains(SESSION_VAR));
+    }
+
+    #[test]
+    fn test_rate_limiter() {
+        let dir = env::temp_dir().join(format!("aoc-rate-{}", std::process::id()));
+        let limiter = RateLimiter::new(dir.join("last-request"), Duration::from_millis(200));
+
+        let first = limiter.wait();
+        let second = limiter.wait();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(first, Ok(Duration::ZERO));
+        assert!(second.unwrap() > Duration::from_millis(100));
+    }
+}
@@ -0,0 +1,423 @@
+//! `aoc submit`: posts an answer to the website and keeps a history of what was submitted.
+//!
+//! The history file has one `day part outcome answer` line per submission, oldest first. An
+//! answer is not posted again when the history already tells how the website will respond: when
+//! the part has been solved, or when the same answer, or one beyond an answer that was too high or
+//! too low, was rejected before.
+
+use std::fmt::{self, Display, Formatter};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use crate::site::{Backend, RateLimiter, CACHE_DIR, YEAR};
+
+/// How the website judged an answer.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// Another answer was submitted too recently. Holds how long to wait, as the website words
+    /// it, if it said so.
+    RateLimited(Option<String>),
+    /// The part is not the one to solve next, because it was solved already or part 1 was not.
+    WrongLevel,
+}
+
+impl Outcome {
+    /// Whether the website said anything about the answer itself.
+    fn is_verdict(&self) -> bool {
+        matches!(
+            self,
+            Outcome::Correct | Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong
+        )
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Outcome::Correct => "correct",
+            Outcome::TooHigh => "too-high",
+            Outcome::TooLow => "too-low",
+            Outcome::Wrong => "wrong",
+            Outcome::RateLimited(_) => "rate-limited",
+            Outcome::WrongLevel => "wrong-level",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Outcome> {
+        let outcome = match name {
+            "correct" => Outcome::Correct,
+            "too-high" => Outcome::TooHigh,
+            "too-low" => Outcome::TooLow,
+            "wrong" => Outcome::Wrong,
+            "rate-limited" => Outcome::RateLimited(None),
+            "wrong-level" => Outcome::WrongLevel,
+            _ => return None,
+        };
+        Some(outcome)
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "that's the right answer"),
+            Outcome::TooHigh => write!(f, "that's not the right answer, it is too high"),
+            Outcome::TooLow => write!(f, "that's not the right answer, it is too low"),
+            Outcome::Wrong => write!(f, "that's not the right answer"),
+            Outcome::RateLimited(Some(wait)) => {
+                write!(f, "an answer was given too recently, wait {wait}")
+            }
+            Outcome::RateLimited(None) => write!(f, "an answer was given too recently"),
+            Outcome::WrongLevel => {
+                write!(
+                    f,
+                    "not the part to solve next, it may have been solved already"
+                )
+            }
+        }
+    }
+}
+
+/// Reads the outcome from the page the website answers a submission with.
+pub fn parse_outcome(page: &str) -> Result<Outcome, String> {
+    let text = article_text(page);
+
+    if text.contains("That's the right answer") {
+        Ok(Outcome::Correct)
+    } else if text.contains("That's not the right answer") {
+        if text.contains("your answer is too high") {
+            Ok(Outcome::TooHigh)
+        } else if text.contains("your answer is too low") {
+            Ok(Outcome::TooLow)
+        } else {
+            Ok(Outcome::Wrong)
+        }
+    } else if text.contains("You gave an answer too recently") {
+        let wait = text
+            .split_once("You have ")
+            .and_then(|(_, rest)| rest.split_once(" left to wait"))
+            .map(|(wait, _)| wait.to_owned());
+        Ok(Outcome::RateLimited(wait))
+    } else if text.contains("You don't seem to be solving the right level") {
+        Ok(Outcome::WrongLevel)
+    } else {
+        let text: String = text.chars().take(200).collect();
+        Err(format!("unexpected response from the website: {text:?}"))
+    }
+}
+
+/// The text of the `<article>` of a page, or of the whole page if it has none, without markup.
+fn article_text(page: &str) -> String {
+    let article = page
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(page, |(article, _)| article);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// One line of the history.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Submission {
+    pub day: u8,
+    pub part: u8,
+    pub outcome: Outcome,
+    pub answer: String,
+}
+
+/// The submissions made so far, backed by a file.
+pub struct History {
+    path: PathBuf,
+    submissions: Vec<Submission>,
+}
+
+impl History {
+    /// Where the history of the website is kept, below the workspace `root`.
+    pub fn path(root: &Path) -> PathBuf {
+        root.join(CACHE_DIR)
+            .join(YEAR.to_string())
+            .join("submissions.txt")
+    }
+
+    /// Reads the history at `path`, which is empty when the file does not exist yet.
+    pub fn load(path: PathBuf) -> Result<History, String> {
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(error) => return Err(format!("unable to read {}: {error}", path.display())),
+        };
+
+        let submissions = parse_history(&text).map_err(|e| format!("{}: {e}", path.display()))?;
+        Ok(History { path, submissions })
+    }
+
+    /// Adds `submission` to the end of the file.
+    pub fn record(&mut self, submission: Submission) -> Result<(), String> {
+        let written = self
+            .path
+            .parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|()| {
+                let mut file = OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(&self.path)?;
+                writeln!(
+                    file,
+                    "{} {} {} {}",
+                    submission.day,
+                    submission.part,
+                    submission.outcome.name(),
+                    submission.answer
+                )
+            });
+        written.map_err(|e| format!("unable to write {}: {e}", self.path.display()))?;
+
+        self.submissions.push(submission);
+        Ok(())
+    }
+
+    /// The outcome the website is known to give for `answer`, without asking it.
+    pub fn known_outcome(
+        &self,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<Option<Outcome>, String> {
+        let mut verdicts = self
+            .submissions
+            .iter()
+            .filter(|s| s.day == day && s.part == part && s.outcome.is_verdict());
+
+        if let Some(correct) = verdicts.clone().find(|s| s.outcome == Outcome::Correct) {
+            return if correct.answer == answer {
+                Ok(Some(Outcome::Correct))
+            } else {
+                Err(format!(
+                    "day {day} part {part} was already solved with {}, not submitting {answer}",
+                    correct.answer
+                ))
+            };
+        }
+
+        let number = answer.parse::<i128>().ok();
+        let known = verdicts.find(|submission| {
+            let beyond = number
+                .zip(submission.answer.parse::<i128>().ok())
+                .is_some_and(|(number, rejected)| match submission.outcome {
+                    Outcome::TooHigh => number >= rejected,
+                    Outcome::TooLow => number <= rejected,
+                    _ => false,
+                });
+            submission.answer == answer || beyond
+        });
+
+        Ok(known.map(|submission| submission.outcome.clone()))
+    }
+}
+
+fn parse_history(text: &str) -> Result<Vec<Submission>, String> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            let mut fields = line.splitn(4, ' ');
+            let mut next = || fields.next().unwrap_or_default();
+            let (day, part, outcome, answer) = (next(), next(), next(), next());
+
+            match (day.parse(), part.parse(), Outcome::from_name(outcome)) {
+                (Ok(day), Ok(part), Some(outcome)) if !answer.is_empty() => Ok(Submission {
+                    day,
+                    part,
+                    outcome,
+                    answer: answer.to_owned(),
+                }),
+                _ => Err(format!(
+                    "line {}: expected `day part outcome answer`",
+                    index + 1
+                )),
+            }
+        })
+        .collect()
+}
+
+/// What `submit` did.
+#[derive(Debug, PartialEq)]
+pub enum Submitted {
+    /// Posted the answer, and the website answered this.
+    Posted(Outcome),
+    /// Did not post the answer, because the history tells what the website would answer.
+    Known(Outcome),
+}
+
+/// Submits `answer` for `part` of `day` through `backend`, unless the history already knows the
+/// outcome. Records the outcome of every answer that is posted.
+pub fn submit(
+    history: &mut History,
+    day: u8,
+    part: u8,
+    answer: &str,
+    backend: &dyn Backend,
+    limiter: &RateLimiter,
+) -> Result<Submitted, String> {
+    if answer.is_empty() || answer.contains(char::is_whitespace) {
+        return Err(format!("{answer:?} cannot be submitted as an answer"));
+    }
+    if let Some(outcome) = history.known_outcome(day, part, answer)? {
+        return Ok(Submitted::Known(outcome));
+    }
+
+    limiter.wait()?;
+    let level = part.to_string();
+    let response = backend.post_form(
+        &format!("/{YEAR}/day/{day}/answer"),
+        &[("level", &level), ("answer", answer)],
+    )?;
+    let outcome = match response.status {
+        200 => parse_outcome(&response.body)?,
+        400 => return Err(String::from("the website rejected the session token")),
+        404 => return Err(format!("day {day} has not unlocked yet")),
+        status => {
+            let reason = response.body.lines().next().unwrap_or_default();
+            return Err(format!("the website answered {status}: {reason}"));
+        }
+    };
+
+    history.record(Submission {
+        day,
+        part,
+        outcome: outcome.clone(),
+        answer: answer.to_owned(),
+    })?;
+    Ok(Submitted::Posted(outcome))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::site::stub::StubServer;
+    use crate::site::HttpBackend;
+
+    fn page(article: &str) -> String {
+        format!("<html><body><main>\n<article><p>{article}</p></article>\n</main></body></html>")
+    }
+
+    const RIGHT: &str = "That's the right answer!  You are <span class=\"day-success\">one gold \
+        star</span> closer to finding the Chief Historian. <a href=\"/2024/day/1#part2\">[Continue \
+        to Part Two]</a>";
+    const TOO_HIGH: &str = "That's not the right answer; your answer is too high.  If you're \
+        stuck, make sure you're using the full input data. Please wait one minute before trying \
+        again. <a href=\"/2024/day/1\">[Return to Day 1]</a>";
+    const WRONG: &str = "That's not the right answer.  If you're stuck, make sure you're using \
+        the full input data. <a href=\"/2024/day/1\">[Return to Day 1]</a>";
+    const TOO_RECENTLY: &str = "You gave an answer too recently; you have to wait after \
+        submitting an answer before trying again.  You have 37s left to wait. \
+        <a href=\"/2024/day/1\">[Return to Day 1]</a>";
+    const WRONG_LEVEL: &str = "You don't seem to be solving the right level.  Did you already \
+        complete it? <a href=\"/2024/day/1\">[Return to Day 1]</a>";
+
+    #[test]
+    fn test_parse_outcome() {
+        assert_eq!(parse_outcome(&page(RIGHT)), Ok(Outcome::Correct));
+        assert_eq!(parse_outcome(&page(TOO_HIGH)), Ok(Outcome::TooHigh));
+        assert_eq!(
+            parse_outcome(&page(&TOO_HIGH.replace("high", "low"))),
+            Ok(Outcome::TooLow)
+        );
+        assert_eq!(parse_outcome(&page(WRONG)), Ok(Outcome::Wrong));
+        assert_eq!(
+            parse_outcome(&page(TOO_RECENTLY)),
+            Ok(Outcome::RateLimited(Some(String::from("37s"))))
+        );
+        assert_eq!(parse_outcome(&page(WRONG_LEVEL)), Ok(Outcome::WrongLevel));
+        assert!(parse_outcome(&page("Puzzle inputs differ by user.")).is_err());
+    }
+
+    #[test]
+    fn test_history() {
+        let text = "1 1 too-high 100\n1 1 too-low 10\n1 1 wrong 50\n\n1 2 correct 1,2,3\n";
+        let submissions = parse_history(text).unwrap();
+        let history = History {
+            path: PathBuf::new(),
+            submissions,
+        };
+
+        assert_eq!(history.known_outcome(1, 1, "50"), Ok(Some(Outcome::Wrong)));
+        assert_eq!(
+            history.known_outcome(1, 1, "120"),
+            Ok(Some(Outcome::TooHigh))
+        );
+        assert_eq!(history.known_outcome(1, 1, "10"), Ok(Some(Outcome::TooLow)));
+        assert_eq!(history.known_outcome(1, 1, "42"), Ok(None));
+        assert_eq!(
+            history.known_outcome(1, 2, "1,2,3"),
+            Ok(Some(Outcome::Correct))
+        );
+        assert!(history.known_outcome(1, 2, "3,2,1").is_err());
+        assert_eq!(history.known_outcome(2, 1, "50"), Ok(None));
+        assert!(history.known_outcome(1, 2, "120").is_err());
+
+        assert!(parse_history("1 1 maybe 50").is_err());
+        assert!(parse_history("1 1 wrong").is_err());
+    }
+
+    #[test]
+    fn test_submit() {
+        let root = std::env::temp_dir().join(format!("aoc-submit-{}", std::process::id()));
+        let limiter = RateLimiter::new(root.join(CACHE_DIR).join("last"), Duration::ZERO);
+        let server = StubServer::start(&[
+            (200, &page(TOO_HIGH)),
+            (200, &page(TOO_RECENTLY)),
+            (200, &page(RIGHT)),
+        ]);
+        let backend = HttpBackend::new(&server.base_url, "abc");
+
+        let mut history = History::load(History::path(&root)).unwrap();
+        let mut outcomes = vec![];
+        for answer in ["100", "100", "101", "42", "42", "42"] {
+            outcomes.push(submit(&mut history, 1, 1, answer, &backend, &limiter));
+        }
+        let requests = server.finish();
+        let reloaded = History::load(History::path(&root)).unwrap();
+        let already_solved = submit(&mut history, 1, 1, "43", &backend, &limiter);
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(
+            outcomes,
+            vec![
+                Ok(Submitted::Posted(Outcome::TooHigh)),
+                Ok(Submitted::Known(Outcome::TooHigh)),
+                Ok(Submitted::Known(Outcome::TooHigh)),
+                Ok(Submitted::Posted(Outcome::RateLimited(Some(String::from(
+                    "37s"
+                ))))),
+                Ok(Submitted::Posted(Outcome::Correct)),
+                Ok(Submitted::Known(Outcome::Correct)),
+            ]
+        );
+        assert_eq!(requests.len(), 3);
+        assert!(requests[0].starts_with("POST /2024/day/1/answer "));
+        assert!(requests[0].ends_with("\r\n\r\nlevel=1&answer=100"));
+        assert!(requests[2].ends_with("\r\n\r\nlevel=1&answer=42"));
+        assert_eq!(reloaded.submissions.len(), 3);
+        assert_eq!(reloaded.submissions[2].outcome, Outcome::Correct);
+        assert!(already_solved
+            .unwrap_err()
+            .contains("already solved with 42"));
+    }
+}
@@ -0,0 +1,184 @@
+//! The text report of `aoc run`: a table with the answers and time of every input, followed by a
+//! line with the totals.
+
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::time::Duration;
+
+use aoc_core::timings::Measurement;
+use aoc_core::Answers;
+
+/// The outcome of solving one day for one input.
+pub struct Row {
+    pub day: u8,
+    pub input: PathBuf,
+    pub read: Measurement,
+    pub outcome: Result<Answers, String>,
+    /// Whether the answers came from the answer cache instead of the solver.
+    pub cached: bool,
+}
+
+impl Row {
+    /// Time spent reading the input and, if that worked, solving it.
+    pub fn time(&self) -> Duration {
+        let solve = self.outcome.as_ref().map_or(Duration::ZERO, |answers| {
+            let timings = &answers.timings;
+            timings.parse.time + timings.part_1.time + timings.part_2.time
+        });
+        self.read.time + solve
+    }
+
+    /// Drops the peak heap measurements, which are taken process wide and so include whatever
+    /// ran at the same time.
+    pub fn forget_peak_heap(&mut self) {
+        self.read.peak_heap = None;
+        if let Ok(answers) = &mut self.outcome {
+            let timings = &mut answers.timings;
+            for phase in [&mut timings.parse, &mut timings.part_1, &mut timings.part_2] {
+                phase.peak_heap = None;
+            }
+        }
+    }
+}
+
+/// Writes one line per row and a line with the totals. `wall_time` is how long solving all rows
+/// took, which is less than the sum of their times when they ran concurrently. Rows with cached
+/// answers show `cached` instead of a time. With `color`, failing rows are printed in red.
+pub fn write_table(
+    mut out: impl Write,
+    rows: &[Row],
+    wall_time: Duration,
+    color: bool,
+) -> io::Result<()> {
+    writeln!(
+        out,
+        "{:<5} {:<20} {:<20} {:>10} Input",
+        "Day", "Part 1", "Part 2", "Time"
+    )?;
+
+    for row in rows {
+        match &row.outcome {
+            Ok(answers) => writeln!(
+                out,
+                "{:<5} {:<20} {:<20} {:>10} {}",
+                row.day,
+                answers.part_1,
+                answers.part_2,
+                if row.cached {
+                    String::from("cached")
+                } else {
+                    format!("{:.2?}", row.time())
+                },
+                row.input.display()
+            )?,
+            Err(error) => {
+                let line = format!("{:<5} error: {}: {error}", row.day, row.input.display());
+                if color {
+                    writeln!(out, "\x1b[1;31m{line}\x1b[0m")?;
+                } else {
+                    writeln!(out, "{line}")?;
+                }
+            }
+        }
+    }
+
+    let failed = rows.iter().filter(|row| row.outcome.is_err()).count();
+    let total: Duration = rows.iter().map(Row::time).sum();
+    writeln!(
+        out,
+        "{:<5} {:<41} {:>10} wall time {wall_time:.2?}",
+        "Total",
+        format!("{} solved, {failed} failed", rows.len() - failed),
+        format!("{total:.2?}")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::timings::Timings;
+
+    use super::*;
+
+    fn measurement(millis: u64) -> Measurement {
+        Measurement {
+            time: Duration::from_millis(millis),
+            peak_heap: Some(1024),
+        }
+    }
+
+    fn row(day: u8, outcome: Result<(&str, &str), &str>) -> Row {
+        let outcome = outcome
+            .map(|(part_1, part_2)| Answers {
+                day,
+                part_1: part_1.to_owned(),
+                part_2: part_2.to_owned(),
+                timings: Timings {
+                    parse: measurement(1),
+                    part_1: measurement(2),
+                    part_2: measurement(3),
+                },
+            })
+            .map_err(str::to_owned);
+
+        Row {
+            day,
+            input: PathBuf::from(format!("day{day:02}/input.txt")),
+            read: measurement(4),
+            outcome,
+            cached: false,
+        }
+    }
+
+    fn render(rows: &[Row], color: bool) -> String {
+        let mut out = vec![];
+        write_table(&mut out, rows, Duration::from_millis(7), color).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_table() {
+        let mut cached = row(3, Ok(("161", "48")));
+        cached.cached = true;
+        if let Ok(answers) = &mut cached.outcome {
+            answers.timings = Timings::default();
+        }
+        let rows = [
+            row(1, Ok(("11", "31"))),
+            row(2, Err("puzzle cannot be solved")),
+            cached,
+        ];
+
+        let table = render(&rows, false);
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines.len(), 5);
+        assert_eq!(
+            lines[1],
+            "1     11                   31                      10.00ms day01/input.txt"
+        );
+        assert_eq!(
+            lines[2],
+            "2     error: day02/input.txt: puzzle cannot be solved"
+        );
+        assert_eq!(
+            lines[3],
+            "3     161                  48                       cached day03/input.txt"
+        );
+        assert!(lines[4].starts_with("Total 2 solved, 1 failed"));
+        assert!(lines[4].ends_with("18.00ms wall time 7.00ms"));
+
+        let table = render(&rows, true);
+        assert!(table.contains("\x1b[1;31m2     error"));
+        assert!(!table.contains("\x1b[1;31m1 "));
+    }
+
+    #[test]
+    fn test_forget_peak_heap() {
+        let mut row = row(1, Ok(("11", "31")));
+        row.forget_peak_heap();
+
+        let timings = row.outcome.unwrap().timings;
+        assert_eq!(row.read.peak_heap, None);
+        assert_eq!(timings.part_2.peak_heap, None);
+        assert_eq!(timings.part_2.time, Duration::from_millis(3));
+    }
+}
@@ -0,0 +1,147 @@
+//! Regression checks of the answers against the ones stored in `dayNN/.answers`.
+//!
+//! The answers file has one `partN: answer` line per part. Blank lines and lines starting with
+//! `#` are ignored. It has no `.txt` extension, so a day directory given as input does not
+//! include it among its inputs.
+
+use std::fmt::{self, Display, Formatter};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc_core::Answers;
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Expected {
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+pub fn answers_path(root: &Path, day: u8) -> PathBuf {
+    root.join(format!("day{day:02}/.answers"))
+}
+
+pub fn read_expected(path: &Path) -> Result<Expected, String> {
+    let text =
+        fs::read_to_string(path).map_err(|e| format!("unable to read {}: {e}", path.display()))?;
+    parse_expected(&text).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+pub fn parse_expected(text: &str) -> Result<Expected, String> {
+    let mut expected = Expected::default();
+
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (key, answer) = line
+            .split_once(':')
+            .ok_or_else(|| format!("line {}: expected `partN: answer`", index + 1))?;
+        let answer = Some(answer.trim().to_owned());
+
+        match key.trim() {
+            "part1" => expected.part_1 = answer,
+            "part2" => expected.part_2 = answer,
+            other => return Err(format!("line {}: unknown part {other:?}", index + 1)),
+        }
+    }
+
+    Ok(expected)
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: String,
+        actual: String,
+    },
+    /// There is no stored answer for this part yet.
+    Unknown,
+}
+
+impl Verdict {
+    pub fn is_failure(&self) -> bool {
+        !matches!(self, Verdict::Pass)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail { expected, actual } => {
+                write!(f, "FAIL (expected {expected}, got {actual})")
+            }
+            Verdict::Unknown => write!(f, "no expected answer"),
+        }
+    }
+}
+
+pub fn compare(expected: &Expected, answers: &Answers) -> [Verdict; 2] {
+    [
+        verdict(expected.part_1.as_deref(), &answers.part_1),
+        verdict(expected.part_2.as_deref(), &answers.part_2),
+    ]
+}
+
+fn verdict(expected: Option<&str>, actual: &str) -> Verdict {
+    match expected {
+        Some(expected) if expected == actual => Verdict::Pass,
+        Some(expected) => Verdict::Fail {
+            expected: expected.to_owned(),
+            actual: actual.to_owned(),
+        },
+        None => Verdict::Unknown,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::timings::Timings;
+
+    use super::*;
+
+    fn answers(part_1: &str, part_2: &str) -> Answers {
+        Answers {
+            day: 1,
+            part_1: part_1.to_owned(),
+            part_2: part_2.to_owned(),
+            timings: Timings::default(),
+        }
+    }
+
+    #[test]
+    fn test_parse_expected() {
+        let expected = parse_expected("# Day 1\npart1: 11\n\npart2:31\n").unwrap();
+        assert_eq!(
+            expected,
+            Expected {
+                part_1: Some(String::from("11")),
+                part_2: Some(String::from("31")),
+            }
+        );
+
+        assert!(parse_expected("part3: 1").is_err());
+        assert!(parse_expected("11").is_err());
+    }
+
+    #[test]
+    fn test_compare() {
+        let expected = parse_expected("part1: 11").unwrap();
+        let [part_1, part_2] = compare(&expected, &answers("11", "31"));
+        assert_eq!(part_1, Verdict::Pass);
+        assert_eq!(part_2, Verdict::Unknown);
+        assert!(part_2.is_failure());
+
+        let [part_1, _] = compare(&expected, &answers("12", "31"));
+        assert_eq!(
+            part_1,
+            Verdict::Fail {
+                expected: String::from("11"),
+                actual: String::from("12")
+            }
+        );
+    }
+}
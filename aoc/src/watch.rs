@@ -0,0 +1,171 @@
+//! `aoc run --watch`: solves again whenever an input, or with `--watch-sources` the source of a
+//! day, changes.
+//!
+//! Files are polled for their modification times, directories are watched recursively. Once a
+//! source changed, the code linked into this process is stale, so every later run goes through
+//! `cargo run`, which rebuilds the runner as needed.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::ffi::OsString;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+/// Modification time of every file below the watched paths, `None` for paths that do not exist.
+#[derive(Debug, Default, PartialEq)]
+pub struct Snapshot(BTreeMap<PathBuf, Option<SystemTime>>);
+
+impl Snapshot {
+    pub fn take(paths: &[PathBuf]) -> Snapshot {
+        let mut snapshot = Snapshot::default();
+        for path in paths {
+            snapshot.add(path);
+        }
+        snapshot
+    }
+
+    fn add(&mut self, path: &Path) {
+        if let Ok(entries) = fs::read_dir(path) {
+            for entry in entries.flatten() {
+                self.add(&entry.path());
+            }
+            return;
+        }
+
+        let modified = fs::metadata(path).and_then(|m| m.modified()).ok();
+        self.0.insert(path.to_path_buf(), modified);
+    }
+
+    /// Paths that were added, removed or modified since `earlier`.
+    pub fn changes_since(&self, earlier: &Snapshot) -> Vec<PathBuf> {
+        let mut changed: Vec<PathBuf> = self
+            .0
+            .iter()
+            .filter(|(path, modified)| earlier.0.get(*path) != Some(modified))
+            .map(|(path, _)| path.clone())
+            .collect();
+        changed.extend(
+            earlier
+                .0
+                .keys()
+                .filter(|path| !self.0.contains_key(*path))
+                .cloned(),
+        );
+        changed.sort();
+        changed
+    }
+}
+
+/// Sources of day `day` in the workspace `root` that are watched with `--watch-sources`.
+pub fn day_sources(root: &Path, day: u8) -> Vec<PathBuf> {
+    let crate_dir = root.join(format!("day{day:02}"));
+    vec![crate_dir.join("Cargo.toml"), crate_dir.join("src")]
+}
+
+/// Calls `solve` once, and again whenever one of the `inputs` changes. When one of the `sources`
+/// changes, `aoc` is run through `cargo run` with `run_args` from then on. Runs until it is
+/// interrupted.
+pub fn watch(
+    inputs: &[PathBuf],
+    sources: &[PathBuf],
+    run_args: &[OsString],
+    mut solve: impl FnMut(),
+) -> ! {
+    let watched: Vec<PathBuf> = inputs.iter().chain(sources).cloned().collect();
+    let mut snapshot = Snapshot::take(&watched);
+    let mut stale = false;
+
+    solve();
+    eprintln!("\nWatching {} for changes", describe(&watched));
+
+    loop {
+        thread::sleep(POLL_INTERVAL);
+        let current = Snapshot::take(&watched);
+        let changed = current.changes_since(&snapshot);
+        if changed.is_empty() {
+            continue;
+        }
+
+        // Editors often write a file in several steps, give them a moment to finish.
+        thread::sleep(POLL_INTERVAL);
+        snapshot = Snapshot::take(&watched);
+        stale |= changed
+            .iter()
+            .any(|path| sources.iter().any(|source| path.starts_with(source)));
+
+        eprintln!("\nChanged: {}", describe(&changed));
+        if stale {
+            rerun(run_args);
+        } else {
+            solve();
+        }
+    }
+}
+
+/// Runs `aoc` with `run_args` through `cargo run`, with the same profile as this process.
+fn rerun(run_args: &[OsString]) {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| OsString::from("cargo"));
+    let mut command = Command::new(cargo);
+    command.args(["run", "--quiet", "--package", "aoc"]);
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+    command.arg("--").args(run_args);
+
+    match command.status() {
+        Ok(status) if !status.success() => eprintln!("error: rebuilt runner failed, {status}"),
+        Ok(_) => {}
+        Err(error) => eprintln!("error: unable to run cargo: {error}"),
+    }
+}
+
+fn describe(paths: &[PathBuf]) -> String {
+    let names: Vec<String> = paths.iter().map(|p| p.display().to_string()).collect();
+    names.join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snapshot_changes() {
+        let dir = env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::write(dir.join("input.txt"), "1").unwrap();
+        fs::write(dir.join("src/lib.rs"), "").unwrap();
+        let watched = [
+            dir.join("input.txt"),
+            dir.join("src"),
+            dir.join("missing.txt"),
+        ];
+
+        let before = Snapshot::take(&watched);
+        let unchanged = Snapshot::take(&watched).changes_since(&before);
+
+        let earlier = SystemTime::now() - Duration::from_secs(60);
+        fs::File::options()
+            .write(true)
+            .open(dir.join("input.txt"))
+            .and_then(|file| file.set_modified(earlier))
+            .unwrap();
+        fs::write(dir.join("src/main.rs"), "").unwrap();
+        let after = Snapshot::take(&watched);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(unchanged.is_empty());
+        assert_eq!(
+            after.changes_since(&before),
+            vec![dir.join("input.txt"), dir.join("src/main.rs")]
+        );
+        assert_eq!(
+            before.changes_since(&after),
+            vec![dir.join("input.txt"), dir.join("src/main.rs")]
+        );
+    }
+}
@@ -0,0 +1,156 @@
+//! Day 1: Historian Hysteria.
+//!
+//! Compares two lists of location IDs, by distance (part 1) and by similarity (part 2).
+//!
+//! ```
+//! let lists = day01::parse_input("3   4\n4   3\n2   5\n1   3\n3   9\n3   3").unwrap();
+//!
+//! assert_eq!(day01::calc_part_1(&lists).unwrap(), 11);
+//! assert_eq!(day01::calc_part_2(&lists).unwrap(), 31);
+//! ```
+
+use std::collections::HashMap;
+
+use aoc_core::num::{checked_add, checked_mul, checked_sum, Int};
+use aoc_core::{Error, ParseError, Solution};
+use aoc_parse::{lines, number_pair};
+use nom::character::complete::space1;
+
+/// Both location lists, each sorted in ascending order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedLists {
+    left: Vec<Int>,
+    right: Vec<Int>,
+}
+
+impl ParsedLists {
+    /// Sorts both lists and pairs them up.
+    pub fn new(mut left: Vec<Int>, mut right: Vec<Int>) -> ParsedLists {
+        left.sort();
+        right.sort();
+
+        ParsedLists { left, right }
+    }
+
+    pub fn left(&self) -> &[Int] {
+        &self.left
+    }
+
+    pub fn right(&self) -> &[Int] {
+        &self.right
+    }
+}
+
+/// Parses lines of two whitespace separated location IDs into the left and right list.
+pub fn parse_input(raw_text: &str) -> Result<ParsedLists, ParseError> {
+    let (left, right) = lines(
+        raw_text,
+        number_pair::<Int, _>(space1),
+        "expected two location IDs separated by spaces",
+    )?
+    .into_iter()
+    .unzip();
+
+    Ok(ParsedLists::new(left, right))
+}
+
+/// Sum of the distances between the n-th smallest IDs of both lists.
+pub fn calc_part_1(parsed_lists: &ParsedLists) -> Result<Int, Error> {
+    let distances = parsed_lists
+        .left
+        .iter()
+        .zip(&parsed_lists.right)
+        .map(|(left, right)| left.abs_diff(*right));
+
+    checked_sum(distances, "the total distance")
+}
+
+/// Sum of every left ID multiplied by the number of times it occurs in the right list.
+pub fn calc_part_2(parsed_lists: &ParsedLists) -> Result<Int, Error> {
+    let mut right_count_lookup: HashMap<Int, Int> = HashMap::new();
+
+    let mut total_similarity = 0;
+    let mut right_iter = parsed_lists.right.iter();
+
+    if let Some(first) = right_iter.next() {
+        let mut cur = *first;
+        let mut count = 1;
+
+        loop {
+            let next = right_iter.next();
+            if next.is_some_and(|&n| n == cur) {
+                count += 1;
+            } else {
+                right_count_lookup.insert(cur, count);
+
+                cur = *next.unwrap_or(&0);
+                count = 1;
+            }
+            if next.is_none() {
+                break;
+            }
+        }
+    }
+
+    for num in &parsed_lists.left {
+        if let Some(&right_count) = right_count_lookup.get(num) {
+            let similarity = checked_mul(*num, right_count, "a similarity score")?;
+            total_similarity = checked_add(total_similarity, similarity, "the similarity score")?;
+        }
+    }
+
+    Ok(total_similarity)
+}
+
+/// [`Solution`] for day 1.
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = ParsedLists;
+    type Answer = Int;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer, Error> {
+        calc_part_1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer, Error> {
+        calc_part_2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_readme_examples() {
+        aoc_core::examples::assert_examples::<Day01>(include_str!("../readme.md"));
+    }
+
+    #[test]
+    fn test_overflow() {
+        let lists = parse_input(&format!("0   {0}\n1   {0}", Int::MAX)).unwrap();
+        assert!(matches!(calc_part_1(&lists), Err(Error::Overflow(_))));
+
+        let lists = parse_input(&format!("{0}   {0}\n{0}   {0}", Int::MAX)).unwrap();
+        assert!(matches!(calc_part_2(&lists), Err(Error::Overflow(_))));
+    }
+
+    #[test]
+    fn test_parse_error_location() {
+        let error = parse_input("3   4\n4   x3").err().unwrap();
+        assert_eq!(
+            error,
+            ParseError::new(2, 5, "x3", "expected two location IDs separated by spaces")
+        );
+
+        let error = parse_input("3   4\n43").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 3));
+    }
+}
@@ -0,0 +1,59 @@
+//! Compares the solver against a slow but obviously correct reference on generated inputs.
+
+use aoc_core::examples::assert_examples;
+use aoc_core::{solve, Error, Solution};
+use day01::Day01;
+use proptest::prelude::*;
+
+/// Pairs up the lists by sorting them, and counts every similarity by scanning the right list.
+struct Reference;
+
+impl Solution for Reference {
+    const DAY: u8 = 1;
+
+    type Input = (Vec<usize>, Vec<usize>);
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(input
+            .lines()
+            .map(|line| {
+                let mut ids = line
+                    .split_whitespace()
+                    .map(|id| id.parse::<usize>().unwrap());
+                (ids.next().unwrap(), ids.next().unwrap())
+            })
+            .unzip())
+    }
+
+    fn part1((left, right): &Self::Input) -> Result<usize, Error> {
+        let (mut left, mut right) = (left.clone(), right.clone());
+        left.sort();
+        right.sort();
+
+        Ok(left.iter().zip(&right).map(|(l, r)| l.abs_diff(*r)).sum())
+    }
+
+    fn part2((left, right): &Self::Input) -> Result<usize, Error> {
+        Ok(left
+            .iter()
+            .map(|l| l * right.iter().filter(|r| *r == l).count())
+            .sum())
+    }
+}
+
+#[test]
+fn test_reference_readme_examples() {
+    assert_examples::<Reference>(include_str!("../readme.md"));
+}
+
+proptest! {
+    #[test]
+    fn test_matches_reference(seed: u64, size in 1..300usize) {
+        let input = aoc_gen::generate(1, size, seed).unwrap();
+        let expected = solve::<Reference>(&input).unwrap();
+        let actual = solve::<Day01>(&input).unwrap();
+
+        prop_assert_eq!((actual.part_1, actual.part_2), (expected.part_1, expected.part_2));
+    }
+}
@@ -0,0 +1,201 @@
+//! Day 2: Red-Nosed Reports.
+//!
+//! Counts the reactor reports whose levels change gradually in a single direction, optionally
+//! tolerating one bad level with the Problem Dampener.
+//!
+//! ```
+//! use day02::{check_row_safety, ReactorSafety};
+//!
+//! let reactor = day02::Reactor::try_from_text("7 6 4 2 1\n1 2 7 8 9").unwrap();
+//!
+//! assert_eq!(check_row_safety(&reactor.data[1]), ReactorSafety::UnsafeDelta);
+//! assert_eq!(day02::solve_part_1(&reactor), 1);
+//! ```
+
+use aoc_core::{Error, ParseError, Solution};
+use aoc_parse::{lines, number_list};
+use nom::character::complete::space1;
+
+/// Largest step between two adjacent levels a safe report may contain.
+pub static MAXIMUM_MEASUREMENT_DELTA: usize = 3;
+
+#[derive(PartialEq, Debug)]
+pub enum ReactorSafety {
+    Safe,
+    /// Two adjacent levels differ more than [`MAXIMUM_MEASUREMENT_DELTA`].
+    UnsafeDelta,
+    /// The levels both increase and decrease.
+    UnevenSlope,
+    /// Two adjacent levels are equal.
+    NoSlope,
+}
+
+/// All reports of the reactor, one row of levels per report.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reactor {
+    pub data: Vec<Vec<usize>>,
+}
+
+impl Reactor {
+    /// Parses one report per line, with levels separated by whitespace.
+    pub fn try_from_text(text: &str) -> Result<Reactor, ParseError> {
+        let data = lines(
+            text,
+            number_list(space1),
+            "expected levels separated by spaces",
+        )?;
+
+        Ok(Reactor { data })
+    }
+}
+
+/// Number of safe reports.
+pub fn solve_part_1(reactor: &Reactor) -> usize {
+    reactor
+        .data
+        .iter()
+        .map(|r| check_row_safety(r))
+        .filter(|f| f == &ReactorSafety::Safe)
+        .count()
+}
+
+/// Number of reports that are safe after removing at most one level.
+pub fn solve_part_2(reactor: &Reactor) -> usize {
+    reactor
+        .data
+        .iter()
+        .map(|r| check_row_safety_with_dampener(r))
+        .filter(|f| f == &ReactorSafety::Safe)
+        .count()
+}
+
+/// Like [`check_row_safety`], but a report is also safe if removing any single level makes it
+/// safe.
+pub fn check_row_safety_with_dampener(reactor_row: &[usize]) -> ReactorSafety {
+    let original_result = check_row_safety(reactor_row);
+    if original_result == ReactorSafety::Safe {
+        return ReactorSafety::Safe;
+    }
+
+    for index in 0..reactor_row.len() {
+        let dampenend_row = reactor_row
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != index)
+            .map(|(_, v)| *v)
+            .collect::<Vec<_>>();
+        if check_row_safety(&dampenend_row) == ReactorSafety::Safe {
+            return ReactorSafety::Safe;
+        }
+    }
+
+    original_result
+}
+
+/// Checks a single report, returning the first problem encountered.
+pub fn check_row_safety(reactor_row: &[usize]) -> ReactorSafety {
+    let mut row_iter = reactor_row.iter().peekable();
+
+    if let Some(&first) = row_iter.next() {
+        let mut prev = first;
+        let sloping_up = row_iter.peek().is_some_and(|&next| prev > *next);
+
+        for &col in row_iter {
+            if prev.abs_diff(col) > MAXIMUM_MEASUREMENT_DELTA {
+                return ReactorSafety::UnsafeDelta;
+            }
+            if prev == col {
+                return ReactorSafety::NoSlope;
+            }
+            if (prev > col) != sloping_up {
+                return ReactorSafety::UnevenSlope;
+            }
+
+            prev = col;
+        }
+    }
+
+    ReactorSafety::Safe
+}
+
+/// [`Solution`] for day 2.
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Reactor;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(Reactor::try_from_text(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer, Error> {
+        Ok(solve_part_1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer, Error> {
+        Ok(solve_part_2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_readme_examples() {
+        aoc_core::examples::assert_examples::<Day02>(include_str!("../readme.md"));
+    }
+
+    #[test]
+    fn test_unsafe_slope() {
+        let slope = [0, 1, 0, 1, 2];
+        let result = check_row_safety(&slope);
+        assert_eq!(result, ReactorSafety::UnevenSlope);
+    }
+
+    #[test]
+    fn test_safe_slope() {
+        let slope = [0, 1, 2, 3, 4];
+        let result = check_row_safety(&slope);
+        assert_eq!(result, ReactorSafety::Safe);
+    }
+
+    #[test]
+    fn test_safe_flat_start_slope() {
+        let slope = [0, 0, 1, 2, 3];
+        let result = check_row_safety(&slope);
+        assert_eq!(result, ReactorSafety::NoSlope);
+    }
+
+    #[test]
+    fn test_safe_plateau_slope() {
+        let slope = [0, 1, 2, 3, 3];
+        let result = check_row_safety(&slope);
+        assert_eq!(result, ReactorSafety::NoSlope);
+    }
+
+    #[test]
+    fn test_unsafe_delta() {
+        let slope = [0, 1, 2, 3, 7];
+        let result = check_row_safety(&slope);
+        assert_eq!(result, ReactorSafety::UnsafeDelta);
+    }
+
+    #[test]
+    fn test_unsafe_delta_with_uneven_slope() {
+        // The unsafe delta should take prevelance over the uneven slope
+        let slope = [1, 2, 3, 4, 0];
+        let result = check_row_safety(&slope);
+        assert_eq!(result, ReactorSafety::UnsafeDelta);
+    }
+
+    #[test]
+    fn test_parse_error_location() {
+        let error = Reactor::try_from_text("7 6 4\n1 2 -7").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 5));
+        assert_eq!(error.text, "-7");
+    }
+}
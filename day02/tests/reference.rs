@@ -0,0 +1,62 @@
+//! Compares the solver against a slow but obviously correct reference on generated inputs.
+
+use aoc_core::examples::assert_examples;
+use aoc_core::{solve, Error, Solution};
+use day02::Day02;
+use proptest::prelude::*;
+
+/// Checks the safety rules literally, and dampens by trying every report with one level removed.
+struct Reference;
+
+fn is_safe(levels: &[usize]) -> bool {
+    let steps_ok = |ok: fn(usize, usize) -> bool| levels.windows(2).all(|w| ok(w[0], w[1]));
+
+    steps_ok(|a, b| b > a && b - a <= 3) || steps_ok(|a, b| a > b && a - b <= 3)
+}
+
+impl Solution for Reference {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Vec<usize>>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(input
+            .lines()
+            .map(|line| line.split(' ').map(|l| l.parse().unwrap()).collect())
+            .collect())
+    }
+
+    fn part1(reports: &Self::Input) -> Result<usize, Error> {
+        Ok(reports.iter().filter(|levels| is_safe(levels)).count())
+    }
+
+    fn part2(reports: &Self::Input) -> Result<usize, Error> {
+        Ok(reports
+            .iter()
+            .filter(|levels| {
+                (0..levels.len()).any(|skip| {
+                    let mut dampened = levels.to_vec();
+                    dampened.remove(skip);
+                    is_safe(&dampened)
+                })
+            })
+            .count())
+    }
+}
+
+#[test]
+fn test_reference_readme_examples() {
+    assert_examples::<Reference>(include_str!("../readme.md"));
+}
+
+proptest! {
+    #[test]
+    fn test_matches_reference(seed: u64, size in 1..300usize) {
+        let input = aoc_gen::generate(2, size, seed).unwrap();
+        let expected = solve::<Reference>(&input).unwrap();
+        let actual = solve::<Day02>(&input).unwrap();
+
+        prop_assert_eq!((actual.part_1, actual.part_2), (expected.part_1, expected.part_2));
+    }
+}
@@ -0,0 +1,187 @@
+//! Day 3: Mull It Over.
+//!
+//! Scans corrupted memory for `mul(x,y)` instructions, optionally honouring `do()` and `don't()`.
+//!
+//! ```
+//! let memory = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+//!
+//! assert_eq!(day03::solve_part_1(memory).unwrap(), 161);
+//! assert_eq!(day03::solve_part_2(memory).unwrap(), 48);
+//! ```
+
+use aoc_core::num::{checked_add, checked_mul, Int};
+use aoc_core::{Error, Solution};
+use aoc_parse::number;
+use nom::bytes::complete::tag;
+use nom::character::complete::digit1;
+use nom::combinator::{map_parser, verify};
+use nom::IResult;
+
+/// A `mul(x,y)` instruction.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Mul {
+    pub x: Int,
+    pub y: Int,
+}
+
+impl Mul {
+    /// `x * y`.
+    pub fn product(&self) -> Result<Int, Error> {
+        checked_mul(self.x, self.y, "the product of a mul instruction")
+    }
+}
+
+/// A `mul` operand, which has 1 to 3 digits.
+fn operand(input: &str) -> IResult<&str, Int> {
+    map_parser(verify(digit1, |digits: &str| digits.len() <= 3), number)(input)
+}
+
+/// Parses a single `mul(x,y)` instruction at the start of `input`. The puzzle gives operands 1
+/// to 3 digits, so an instruction with a longer operand, like `mul(1234,5)`, is corrupted.
+pub fn parse_mul(input: &str) -> IResult<&str, Mul> {
+    let (input, _) = tag("mul(")(input)?;
+    let (input, x) = operand(input)?;
+    let (input, _) = tag(",")(input)?;
+    let (input, y) = operand(input)?;
+    let (input, _) = tag(")")(input)?;
+
+    Ok((input, Mul { x, y }))
+}
+
+fn parse_do(input: &str) -> IResult<&str, &str> {
+    tag("do()")(input)
+}
+
+fn parse_dont(input: &str) -> IResult<&str, &str> {
+    tag("don't()")(input)
+}
+
+/// `input` without its first character.
+fn skip_char(input: &str) -> &str {
+    let mut chars = input.chars();
+    chars.next();
+    chars.as_str()
+}
+
+const SUM: &str = "the sum of the mul instructions";
+
+/// Sum of all `mul` instructions that are not disabled by a preceding `don't()`.
+pub fn solve_part_2(input: &str) -> Result<Int, Error> {
+    let mut input_slice = input;
+    let mut sum = 0;
+    let mut execute_instruction = true;
+
+    while input_slice.len() >= 8 {
+        if let Ok((remainder, _)) = parse_do(input_slice) {
+            execute_instruction = true;
+            input_slice = remainder;
+            continue;
+        }
+
+        if let Ok((remainder, _)) = parse_dont(input_slice) {
+            execute_instruction = false;
+            input_slice = remainder;
+            continue;
+        }
+
+        if !execute_instruction {
+            input_slice = skip_char(input_slice);
+            continue;
+        }
+
+        match parse_mul(input_slice) {
+            Ok((remainder, mul)) => {
+                input_slice = remainder;
+
+                sum = checked_add(sum, mul.product()?, SUM)?;
+            }
+            Err(_) => {
+                input_slice = skip_char(input_slice);
+            }
+        }
+    }
+
+    Ok(sum)
+}
+
+/// Sum of all `mul` instructions in the memory.
+pub fn solve_part_1(input: &str) -> Result<Int, Error> {
+    let mut input_slice = input;
+    let mut sum = 0;
+
+    while input_slice.len() >= 8 {
+        match parse_mul(input_slice) {
+            Ok((remainder, mul)) => {
+                input_slice = remainder;
+                sum = checked_add(sum, mul.product()?, SUM)?;
+            }
+            Err(_) => {
+                input_slice = skip_char(input_slice);
+            }
+        }
+    }
+
+    Ok(sum)
+}
+
+/// [`Solution`] for day 3.
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    // The instructions are scanned straight from the corrupted memory.
+    type Input = String;
+    type Answer = Int;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(input.to_owned())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer, Error> {
+        solve_part_1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer, Error> {
+        solve_part_2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_readme_examples() {
+        aoc_core::examples::assert_examples::<Day03>(include_str!("../readme.md"));
+    }
+
+    #[test]
+    fn test_single_tag() {
+        let tag = "mul(1,2)";
+        let result = parse_mul(tag);
+        assert_eq!(result, Ok(("", Mul { x: 1, y: 2 })));
+
+        assert!(parse_mul("mul(1234,5)").is_err());
+    }
+
+    #[test]
+    fn test_long_operands_are_corrupted() {
+        let memory = "mul(1234,5)mul(2,3)mul(4,5678)mul(999,1)";
+        assert_eq!(solve_part_1(memory).unwrap(), 1005);
+        assert_eq!(solve_part_2(memory).unwrap(), 1005);
+    }
+
+    #[test]
+    fn test_product_overflow() {
+        let mul = Mul { x: Int::MAX, y: 2 };
+        assert!(matches!(mul.product(), Err(Error::Overflow(_))));
+    }
+
+    #[test]
+    fn test_multibyte_memory() {
+        let memory = "é€mul(2,3)ü don't()mul(1,1)😀do()mul(4,4)";
+        assert_eq!(solve_part_1(memory).unwrap(), 23);
+        assert_eq!(solve_part_2(memory).unwrap(), 22);
+    }
+}
@@ -0,0 +1,75 @@
+//! Compares the solver against a slow but obviously correct reference on generated inputs.
+
+use aoc_core::examples::assert_examples;
+use aoc_core::{solve, Error, Solution};
+use day03::Day03;
+use proptest::prelude::*;
+
+/// Tries to read an instruction at every byte offset of the memory.
+struct Reference;
+
+/// The product of a `mul(X,Y)` at the start of `memory`.
+fn mul_at(memory: &[u8]) -> Option<usize> {
+    let rest = memory.strip_prefix(b"mul(")?;
+    let comma = rest.iter().position(|&b| b == b',')?;
+    let close = rest.iter().position(|&b| b == b')')?;
+    let operand = |digits: &[u8]| -> Option<usize> {
+        let valid = (1..=3).contains(&digits.len()) && digits.iter().all(u8::is_ascii_digit);
+        valid.then(|| std::str::from_utf8(digits).ok()?.parse().ok())?
+    };
+
+    Some(operand(rest.get(..comma)?)? * operand(rest.get(comma + 1..close)?)?)
+}
+
+fn sum_muls(memory: &str, toggles: bool) -> usize {
+    let memory = memory.as_bytes();
+    let mut enabled = true;
+    let mut sum = 0;
+
+    for start in 0..memory.len() {
+        let rest = &memory[start..];
+        if rest.starts_with(b"do()") {
+            enabled = true;
+        } else if rest.starts_with(b"don't()") {
+            enabled = !toggles;
+        } else if enabled {
+            sum += mul_at(rest).unwrap_or(0);
+        }
+    }
+    sum
+}
+
+impl Solution for Reference {
+    const DAY: u8 = 3;
+
+    type Input = String;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(input.to_string())
+    }
+
+    fn part1(memory: &Self::Input) -> Result<usize, Error> {
+        Ok(sum_muls(memory, false))
+    }
+
+    fn part2(memory: &Self::Input) -> Result<usize, Error> {
+        Ok(sum_muls(memory, true))
+    }
+}
+
+#[test]
+fn test_reference_readme_examples() {
+    assert_examples::<Reference>(include_str!("../readme.md"));
+}
+
+proptest! {
+    #[test]
+    fn test_matches_reference(seed: u64, size in 1..300usize) {
+        let input = aoc_gen::generate(3, size, seed).unwrap();
+        let expected = solve::<Reference>(&input).unwrap();
+        let actual = solve::<Day03>(&input).unwrap();
+
+        prop_assert_eq!((actual.part_1, actual.part_2), (expected.part_1, expected.part_2));
+    }
+}
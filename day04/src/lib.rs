@@ -0,0 +1,110 @@
+//! Day 4: Ceres Search.
+//!
+//! Word search on a rectangular grid of letters.
+//!
+//! ```
+//! let puzzle = day04::parse_input("XZZZ\nMZZZ\nAZZZ\nSZZZ").unwrap();
+//!
+//! assert_eq!(day04::solve_part_1(&puzzle, "XMAS"), 1);
+//! ```
+
+use aoc_core::{Error, ParseError, Solution};
+use aoc_grid::{Coord, Direction, Grid};
+
+pub mod viz;
+
+/// Parses the word search into a grid of letters.
+pub fn parse_input(puzzle: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(puzzle, |_, c| Ok(c))
+}
+
+/// Whether `search` can be read from `start` in `direction`.
+fn reads(puzzle: &Grid<char>, start: Coord, direction: Direction, search: &str) -> bool {
+    let mut letters = puzzle.ray(start, direction).map(|(_, &c)| c);
+    search.chars().all(|c| letters.next() == Some(c))
+}
+
+/// Whether `search` can be read forwards or backwards along the diagonal from `start`.
+fn reads_either_way(puzzle: &Grid<char>, start: Coord, direction: Direction, search: &str) -> bool {
+    let Some(end) =
+        (1..search.chars().count()).try_fold(start, |coord, _| puzzle.step(coord, direction))
+    else {
+        return false;
+    };
+
+    reads(puzzle, start, direction, search) || reads(puzzle, end, direction.opposite(), search)
+}
+
+/// Counts the places where `search` appears twice in the shape of an X, on both diagonals of
+/// the same square. Either diagonal may be read backwards.
+pub fn solve_part_2(puzzle: &Grid<char>, search: &str) -> usize {
+    let span = search.chars().count().saturating_sub(1);
+
+    puzzle
+        .coords()
+        .filter(|&top_left| {
+            let top_right = Coord::new(top_left.row, top_left.col + span);
+
+            reads_either_way(puzzle, top_left, Direction::DownRight, search)
+                && reads_either_way(puzzle, top_right, Direction::DownLeft, search)
+        })
+        .count()
+}
+
+/// Counts every occurrence of `search` horizontally, vertically or diagonally, forwards and
+/// backwards.
+pub fn solve_part_1(puzzle: &Grid<char>, search: &str) -> usize {
+    puzzle
+        .coords()
+        .map(|start| {
+            Direction::ALL
+                .iter()
+                .filter(|&&direction| reads(puzzle, start, direction, search))
+                .count()
+        })
+        .sum()
+}
+
+/// [`Solution`] for day 4, searching for `XMAS` and the `MAS` cross.
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Grid<char>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer, Error> {
+        Ok(solve_part_1(input, "XMAS"))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer, Error> {
+        Ok(solve_part_2(input, "MAS"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_readme_examples() {
+        aoc_core::examples::assert_examples::<Day04>(include_str!("../readme.md"));
+    }
+
+    #[test]
+    fn test_part_1_first_col() {
+        let word = "XMAS";
+        let puzzle = "XZZZ
+MZZZ
+AZZZ
+SZZZ";
+        let result = 1;
+        let sum = solve_part_1(&parse_input(puzzle).unwrap(), word);
+        assert_eq!(result, sum);
+    }
+}
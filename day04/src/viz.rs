@@ -0,0 +1,160 @@
+//! Animation of the word search: the letters of every match light up as the search goes down the
+//! grid, one row at a time.
+
+use std::collections::HashSet;
+
+use aoc_core::Error;
+use aoc_grid::{Coord, Direction, Grid};
+use aoc_viz::{Cell, Frame, Rgb, Sink};
+
+use crate::{reads, reads_either_way};
+
+const LETTER: Rgb = Rgb::GREY;
+const BACKGROUND: Rgb = Rgb::BLACK;
+/// The row being searched.
+const SEARCHING: Rgb = Rgb(48, 48, 48);
+/// Letters of a straight match.
+const MATCH: Rgb = Rgb::YELLOW;
+/// Letters of a cross.
+const CROSS: Rgb = Rgb::MAGENTA;
+
+/// The grid with the letters in `found` on `highlight`, and the row `searching` marked.
+pub fn frame(
+    puzzle: &Grid<char>,
+    found: &HashSet<Coord>,
+    highlight: Rgb,
+    searching: Option<usize>,
+) -> Frame {
+    Frame::render(puzzle, |coord, &letter| {
+        if found.contains(&coord) {
+            Cell::new(letter, Rgb::BLACK, highlight)
+        } else if searching == Some(coord.row) {
+            Cell::new(letter, Rgb::WHITE, SEARCHING)
+        } else {
+            Cell::new(letter, LETTER, BACKGROUND)
+        }
+    })
+}
+
+/// The `len` coordinates from `start` in `direction`.
+fn word(start: Coord, direction: Direction, len: usize) -> impl Iterator<Item = Coord> {
+    std::iter::successors(Some(start), move |&coord| coord.step(direction)).take(len)
+}
+
+/// Sends a frame for every row to `sink`, highlighting the letters of the occurrences of `search`
+/// that start on it or above, like [`solve_part_1`](crate::solve_part_1) counts them.
+pub fn matches(puzzle: &Grid<char>, search: &str, sink: &mut dyn Sink) -> Result<(), Error> {
+    let len = search.chars().count();
+    let mut found = HashSet::new();
+    let mut count = 0;
+
+    for row in 0..puzzle.height() {
+        for col in 0..puzzle.width() {
+            let start = Coord::new(row, col);
+            for &direction in &Direction::ALL {
+                if reads(puzzle, start, direction, search) {
+                    found.extend(word(start, direction, len));
+                    count += 1;
+                }
+            }
+        }
+
+        let caption = format!("{search}: {count} found");
+        sink.frame(&frame(puzzle, &found, MATCH, Some(row)).with_caption(caption))
+            .map_err(Error::Output)?;
+    }
+    Ok(())
+}
+
+/// Sends a frame for every row to `sink`, highlighting the crosses of `search` with their top
+/// left corner on it or above, like [`solve_part_2`](crate::solve_part_2) counts them.
+pub fn crosses(puzzle: &Grid<char>, search: &str, sink: &mut dyn Sink) -> Result<(), Error> {
+    let len = search.chars().count();
+    let span = len.saturating_sub(1);
+    let mut found = HashSet::new();
+    let mut count = 0;
+
+    for row in 0..puzzle.height() {
+        for col in 0..puzzle.width() {
+            let top_left = Coord::new(row, col);
+            let top_right = Coord::new(row, col + span);
+            if reads_either_way(puzzle, top_left, Direction::DownRight, search)
+                && reads_either_way(puzzle, top_right, Direction::DownLeft, search)
+            {
+                found.extend(word(top_left, Direction::DownRight, len));
+                found.extend(word(top_right, Direction::DownLeft, len));
+                count += 1;
+            }
+        }
+
+        let caption = format!("{search} crosses: {count} found");
+        sink.frame(&frame(puzzle, &found, CROSS, Some(row)).with_caption(caption))
+            .map_err(Error::Output)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use super::*;
+    use crate::parse_input;
+
+    #[derive(Default)]
+    struct Frames(Vec<Frame>);
+
+    impl Sink for Frames {
+        fn frame(&mut self, frame: &Frame) -> io::Result<()> {
+            self.0.push(frame.clone());
+            Ok(())
+        }
+    }
+
+    fn highlighted(frame: &Frame, highlight: Rgb) -> String {
+        frame
+            .cells
+            .rows()
+            .map(|row| {
+                let line: String = row
+                    .iter()
+                    .map(|cell| {
+                        if cell.bg == highlight {
+                            cell.glyph
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect();
+                line + "\n"
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_matches() {
+        let puzzle = parse_input("XMASZ\nZZZZZ\nSAMXZ").unwrap();
+        let mut frames = Frames::default();
+        matches(&puzzle, "XMAS", &mut frames).unwrap();
+
+        assert_eq!(frames.0.len(), 3);
+        assert_eq!(frames.0[0].caption, "XMAS: 1 found");
+        assert_eq!(frames.0[2].caption, "XMAS: 2 found");
+        assert_eq!(highlighted(&frames.0[2], MATCH), "XMAS.\n.....\nSAMX.\n");
+        assert_eq!(
+            highlighted(&frames.0[1], SEARCHING),
+            ".....\nZZZZZ\n.....\n"
+        );
+    }
+
+    #[test]
+    fn test_crosses() {
+        let puzzle = parse_input("MZS\nZAZ\nMZS\nZZZ").unwrap();
+        let mut frames = Frames::default();
+        crosses(&puzzle, "MAS", &mut frames).unwrap();
+
+        assert_eq!(frames.0.len(), 4);
+        assert_eq!(frames.0[3].caption, "MAS crosses: 1 found");
+        assert_eq!(highlighted(&frames.0[3], CROSS), "M.S\n.A.\nM.S\n...\n");
+    }
+}
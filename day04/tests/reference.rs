@@ -0,0 +1,81 @@
+//! Compares the solver against a slow but obviously correct reference on generated inputs.
+
+use aoc_core::examples::assert_examples;
+use aoc_core::{solve, Error, Solution};
+use day04::Day04;
+use proptest::prelude::*;
+
+/// Spells out the words letter by letter from every cell.
+struct Reference;
+
+/// The letter at `row + dr * i`, `col + dc * i`, if that is on the grid.
+fn letter(
+    grid: &[Vec<char>],
+    (row, col): (usize, usize),
+    (dr, dc): (isize, isize),
+    i: isize,
+) -> Option<char> {
+    let row = row.checked_add_signed(dr * i)?;
+    let col = col.checked_add_signed(dc * i)?;
+    grid.get(row)?.get(col).copied()
+}
+
+impl Solution for Reference {
+    const DAY: u8 = 4;
+
+    type Input = Vec<Vec<char>>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(input.lines().map(|line| line.chars().collect()).collect())
+    }
+
+    fn part1(grid: &Self::Input) -> Result<usize, Error> {
+        let mut count = 0;
+        for row in 0..grid.len() {
+            for col in 0..grid[row].len() {
+                for dr in -1..=1 {
+                    for dc in -1..=1 {
+                        let word: String = (0..4)
+                            .filter_map(|i| letter(grid, (row, col), (dr, dc), i))
+                            .collect();
+                        count += usize::from((dr, dc) != (0, 0) && word == "XMAS");
+                    }
+                }
+            }
+        }
+        Ok(count)
+    }
+
+    fn part2(grid: &Self::Input) -> Result<usize, Error> {
+        let mut count = 0;
+        for row in 0..grid.len() {
+            for col in 0..grid[row].len() {
+                let diagonal = |dc: isize| -> String {
+                    (-1..=1)
+                        .filter_map(|i| letter(grid, (row, col), (1, dc), i))
+                        .collect()
+                };
+                let is_mas = |word: String| word == "MAS" || word == "SAM";
+                count += usize::from(is_mas(diagonal(1)) && is_mas(diagonal(-1)));
+            }
+        }
+        Ok(count)
+    }
+}
+
+#[test]
+fn test_reference_readme_examples() {
+    assert_examples::<Reference>(include_str!("../readme.md"));
+}
+
+proptest! {
+    #[test]
+    fn test_matches_reference(seed: u64, size in 1..40usize) {
+        let input = aoc_gen::generate(4, size, seed).unwrap();
+        let expected = solve::<Reference>(&input).unwrap();
+        let actual = solve::<Day04>(&input).unwrap();
+
+        prop_assert_eq!((actual.part_1, actual.part_2), (expected.part_1, expected.part_2));
+    }
+}
@@ -0,0 +1,275 @@
+//! Day 5: Print Queue.
+//!
+//! Checks safety manual updates against page ordering rules, and repairs the ones printed in the
+//! wrong order.
+//!
+//! ```
+//! use day05::PageOrderingRules;
+//!
+//! let (rules, manual) = day05::parse_input("47|53\n97|47\n\n53,47,97").unwrap();
+//!
+//! assert!(!rules.has_correct_order(&manual[0]));
+//! assert_eq!(rules.sort_pages(&manual[0]).unwrap(), vec![97, 47, 53]);
+//! ```
+
+use std::fmt::{self, Display, Formatter};
+
+use aoc_core::num::{checked_add, Int};
+use aoc_core::{Error, ParseError, Solution};
+use aoc_parse::{number_list, number_pair, sections};
+use nom::bytes::complete::tag;
+use nom::combinator::map;
+use nom::IResult;
+
+/// The rule `left|right`: page `left` has to be printed before page `right`.
+#[derive(PartialEq, Debug, Clone)]
+pub struct PageOrderingRule {
+    pub left: Int,
+    pub right: Int,
+}
+
+impl Display for PageOrderingRule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.left, self.right)
+    }
+}
+
+/// Operations on a complete set of ordering rules.
+pub trait PageOrderingRules {
+    /// Rules of which both pages occur in `pages`, the others do not apply to the update.
+    fn get_relevant_rules(&self, pages: &[Int]) -> Vec<&PageOrderingRule>;
+    /// Whether `pages` satisfies every relevant rule.
+    fn has_correct_order(&self, pages: &[Int]) -> bool;
+    /// Reorders `pages` so that they satisfy the relevant rules. Fails when the relevant rules
+    /// contain a cycle, so that no order satisfies them.
+    fn sort_pages(&self, pages: &[Int]) -> Result<Vec<Int>, Error>;
+}
+
+impl PageOrderingRules for Vec<PageOrderingRule> {
+    fn get_relevant_rules(&self, pages: &[Int]) -> Vec<&PageOrderingRule> {
+        self.iter()
+            .filter(|&r| pages.contains(&r.left) && pages.contains(&r.right))
+            .collect()
+    }
+
+    fn has_correct_order(&self, pages: &[Int]) -> bool {
+        let relevant_rules = self.get_relevant_rules(pages);
+
+        relevant_rules.iter().all(|rule| {
+            pages.iter().position(|num| *num == rule.right)
+                > pages.iter().position(|num| *num == rule.left)
+        })
+    }
+
+    fn sort_pages(&self, pages: &[Int]) -> Result<Vec<Int>, Error> {
+        let relevant_rules = self.get_relevant_rules(pages);
+
+        // A comparator sort cannot cope with rule sets that do not form a total order, so
+        // repeatedly take the first page that no remaining page has to precede.
+        let mut remaining = Vec::from(pages);
+        let mut result = Vec::with_capacity(pages.len());
+
+        while !remaining.is_empty() {
+            let next = remaining
+                .iter()
+                .position(|&page| {
+                    !relevant_rules
+                        .iter()
+                        .any(|r| r.right == page && remaining.contains(&r.left))
+                })
+                .ok_or_else(|| {
+                    Error::Unsolvable(format!(
+                        "the ordering rules for pages {remaining:?} contain a cycle"
+                    ))
+                })?;
+
+            result.push(remaining.remove(next));
+        }
+
+        Ok(result)
+    }
+}
+
+/// Parses the ordering rules, a blank line, and one comma separated update per line.
+pub fn parse_input(input: &str) -> Result<(Vec<PageOrderingRule>, Vec<Vec<Int>>), ParseError> {
+    let (rules, updates) = match sections(input).as_slice() {
+        [] => return Ok((vec![], vec![])),
+        [rules] => (*rules, None),
+        [rules, updates] => (*rules, Some(*updates)),
+        [_, _, extra, ..] => {
+            let line = extra.text.lines().next().unwrap_or_default();
+            return Err(ParseError::at(
+                extra.first_line,
+                line,
+                line,
+                "expected only ordering rules and updates",
+            ));
+        }
+    };
+
+    let rules = rules.lines(parse_ordering_rule, "expected an ordering rule like 47|53")?;
+    let manual = match updates {
+        Some(updates) => updates.lines(parse_pages, "expected comma separated page numbers")?,
+        None => vec![],
+    };
+
+    Ok((rules, manual))
+}
+
+const SUM: &str = "the sum of the middle page numbers";
+
+/// Sum of the middle pages of the updates that are already correctly ordered.
+pub fn solve_part_1(rules: &Vec<PageOrderingRule>, manual: &Vec<Vec<Int>>) -> Result<Int, Error> {
+    let mut count = 0;
+    for pagelist in manual {
+        if rules.has_correct_order(pagelist) {
+            let middle_page = pagelist.get(pagelist.len().div_euclid(2)).unwrap();
+            count = checked_add(count, *middle_page, SUM)?;
+        }
+    }
+
+    Ok(count)
+}
+
+/// Sum of the middle pages of the incorrectly ordered updates, after sorting them.
+pub fn solve_part_2(rules: &Vec<PageOrderingRule>, manual: &Vec<Vec<Int>>) -> Result<Int, Error> {
+    let mut count = 0;
+    for pagelist in manual {
+        if !rules.has_correct_order(pagelist) {
+            let sorted_pages = rules.sort_pages(pagelist)?;
+            let middle_page = sorted_pages.get(sorted_pages.len().div_euclid(2)).unwrap();
+            count = checked_add(count, *middle_page, SUM)?;
+        }
+    }
+
+    Ok(count)
+}
+
+/// Parses a single `left|right` rule.
+pub fn parse_ordering_rule(input: &str) -> IResult<&str, PageOrderingRule> {
+    map(number_pair(tag("|")), |(left, right)| PageOrderingRule {
+        left,
+        right,
+    })(input)
+}
+
+/// Parses a single comma separated update.
+pub fn parse_pages(input: &str) -> IResult<&str, Vec<Int>> {
+    number_list(tag(","))(input)
+}
+
+/// [`Solution`] for day 5.
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = (Vec<PageOrderingRule>, Vec<Vec<Int>>);
+    type Answer = Int;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1((rules, manual): &Self::Input) -> Result<Self::Answer, Error> {
+        solve_part_1(rules, manual)
+    }
+
+    fn part2((rules, manual): &Self::Input) -> Result<Self::Answer, Error> {
+        solve_part_2(rules, manual)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_readme_examples() {
+        aoc_core::examples::assert_examples::<Day05>(include_str!("../readme.md"));
+    }
+
+    #[test]
+    fn test_parse_rule() {
+        let result = parse_ordering_rule("47|53");
+        assert_eq!(
+            result,
+            Ok((
+                "",
+                PageOrderingRule {
+                    left: 47,
+                    right: 53
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_pages() {
+        let result = parse_pages("75,47,61,53,29");
+        assert_eq!(result, Ok(("", vec![75, 47, 61, 53, 29])));
+    }
+
+    #[test]
+    fn test_page_sort() {
+        let ruleset_1 = vec![
+            PageOrderingRule { left: 1, right: 2 },
+            PageOrderingRule { left: 3, right: 4 },
+            PageOrderingRule { left: 4, right: 1 },
+        ];
+
+        // Problematic operations
+        //                               1 | 5
+        // 1    5                        5 | 10
+        // 1    5     10
+        // 1    5     10                 5 | 9
+        // 1    5 9   10
+        // 1    5 9   10                 8 | 10
+        // 1    5 9 8 10
+        // 1    5 9 8 10                 8 | 9
+
+        // The two ends of the book are easy to find
+
+        // The only left with for which no right exists
+        // The only right with for which no left exists
+
+        // What rule to apply next?
+
+        assert_eq!(
+            vec![3, 4, 1, 2],
+            ruleset_1.sort_pages(&[1, 2, 3, 4]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_cyclic_rules() {
+        let (rules, manual) = parse_input("1|2\n2|3\n3|1\n\n3,2,1").unwrap();
+        assert!(matches!(
+            rules.sort_pages(&manual[0]),
+            Err(Error::Unsolvable(_))
+        ));
+        assert!(matches!(
+            solve_part_2(&rules, &manual),
+            Err(Error::Unsolvable(_))
+        ));
+    }
+
+    #[test]
+    fn test_overflow() {
+        let (rules, manual) = parse_input(&format!("1|2\n\n{0}\n{0}", Int::MAX)).unwrap();
+        assert!(matches!(
+            solve_part_1(&rules, &manual),
+            Err(Error::Overflow(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_error_location() {
+        let error = parse_input("47|53\n97|x3\n\n75,47").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 4));
+
+        let error = parse_input("47|53\n\n75,47,61,").err().unwrap();
+        assert_eq!((error.line, error.column), (3, 9));
+        assert_eq!(error.text, ",");
+    }
+}
@@ -0,0 +1,97 @@
+//! Compares the solver against a slow but obviously correct reference on generated inputs.
+
+use std::collections::HashSet;
+
+use aoc_core::examples::assert_examples;
+use aoc_core::{solve, Error, Solution};
+use day05::Day05;
+use proptest::prelude::*;
+
+/// Checks every pair of pages of an update against the rules, and orders updates by repeatedly
+/// taking a page that no rule wants after one of the remaining pages.
+struct Reference;
+
+type Rules = HashSet<(usize, usize)>;
+
+fn is_ordered(rules: &Rules, update: &[usize]) -> bool {
+    (0..update.len())
+        .all(|i| (i + 1..update.len()).all(|j| !rules.contains(&(update[j], update[i]))))
+}
+
+fn reorder(rules: &Rules, update: &[usize]) -> Vec<usize> {
+    let mut remaining = update.to_vec();
+    let mut ordered = vec![];
+
+    while !remaining.is_empty() {
+        let first = (0..remaining.len())
+            .find(|&i| {
+                remaining
+                    .iter()
+                    .all(|&other| !rules.contains(&(other, remaining[i])))
+            })
+            .expect("Rules are a total order on every update");
+        ordered.push(remaining.remove(first));
+    }
+    ordered
+}
+
+impl Solution for Reference {
+    const DAY: u8 = 5;
+
+    type Input = (Rules, Vec<Vec<usize>>);
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let (rules, updates) = input.split_once("\n\n").unwrap();
+        let rules = rules
+            .lines()
+            .map(|rule| {
+                let (before, after) = rule.split_once('|').unwrap();
+                (before.parse().unwrap(), after.parse().unwrap())
+            })
+            .collect();
+        let updates = updates
+            .lines()
+            .map(|update| {
+                update
+                    .split(',')
+                    .map(|page| page.parse().unwrap())
+                    .collect()
+            })
+            .collect();
+
+        Ok((rules, updates))
+    }
+
+    fn part1((rules, updates): &Self::Input) -> Result<usize, Error> {
+        Ok(updates
+            .iter()
+            .filter(|update| is_ordered(rules, update))
+            .map(|update| update[update.len() / 2])
+            .sum())
+    }
+
+    fn part2((rules, updates): &Self::Input) -> Result<usize, Error> {
+        Ok(updates
+            .iter()
+            .filter(|update| !is_ordered(rules, update))
+            .map(|update| reorder(rules, update)[update.len() / 2])
+            .sum())
+    }
+}
+
+#[test]
+fn test_reference_readme_examples() {
+    assert_examples::<Reference>(include_str!("../readme.md"));
+}
+
+proptest! {
+    #[test]
+    fn test_matches_reference(seed: u64, size in 1..100usize) {
+        let input = aoc_gen::generate(5, size, seed).unwrap();
+        let expected = solve::<Reference>(&input).unwrap();
+        let actual = solve::<Day05>(&input).unwrap();
+
+        prop_assert_eq!((actual.part_1, actual.part_2), (expected.part_1, expected.part_2));
+    }
+}
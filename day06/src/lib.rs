@@ -0,0 +1,469 @@
+//! Day 6: Guard Gallivant.
+//!
+//! Simulates the patrol of a guard who turns right at every obstruction, and finds the places
+//! where a single new obstruction would trap the guard in a loop.
+//!
+//! ```
+//! use day06::SituationMap;
+//!
+//! let board = SituationMap::try_from("#...\n....\n.#..\n^...").unwrap();
+//!
+//! assert_eq!(day06::solve_part_1(board.clone()).unwrap(), 6);
+//! assert_eq!(day06::solve_part_2(board).unwrap(), 0);
+//! ```
+
+use std::collections::HashSet;
+use std::fmt::{self, Display, Formatter};
+
+use aoc_core::{Error, ParseError, Solution};
+pub use aoc_grid::Coord;
+use aoc_grid::{Direction, Grid};
+
+pub mod viz;
+
+/// The guard walks in circles without any new obstruction, so it never leaves the map.
+fn guard_is_trapped() -> Error {
+    Error::Unsolvable(String::from(
+        "the guard walks in circles and never leaves the map",
+    ))
+}
+
+/// Walks the guard along its patrol, trying a new obstruction on every free tile in front of
+/// it. Returns the positions where the obstruction makes the guard walk in circles. Calls
+/// `on_test` with the board, the tried position and the positions found so far after every try.
+fn find_traps(
+    mut board: SituationMap,
+    mut on_test: impl FnMut(&SituationMap, Coord, &HashSet<Coord>) -> Result<(), Error>,
+) -> Result<HashSet<Coord>, Error> {
+    let mut coords: HashSet<Coord> = HashSet::new();
+    let mut states: HashSet<(Coord, Orientation)> = HashSet::new();
+
+    loop {
+        if !states.insert((board.player.coords, board.player.orientation)) {
+            return Err(guard_is_trapped());
+        }
+
+        if let Some((tested, &element)) = board.what_is_in_front(&board.player) {
+            if element == MapElements::Free {
+                if let Some(c) =
+                    board.test_circular_path(board.player.coords, board.player.orientation)
+                {
+                    coords.insert(c);
+                };
+                on_test(&board, tested, &coords)?;
+            }
+        }
+
+        if board.step().is_none() {
+            break;
+        }
+    }
+
+    Ok(coords)
+}
+
+/// Number of distinct positions where a new obstruction would make the guard walk in circles.
+pub fn solve_part_2(board: SituationMap) -> Result<usize, Error> {
+    Ok(find_traps(board, |_, _, _| Ok(()))?.len())
+}
+
+/// Moves the guard until it leaves the map, calling `on_step` with the board after every step.
+fn patrol(
+    board: &mut SituationMap,
+    mut on_step: impl FnMut(&SituationMap) -> Result<(), Error>,
+) -> Result<(), Error> {
+    let mut states: HashSet<(Coord, Orientation)> = HashSet::new();
+
+    while board.step().is_some() {
+        if !states.insert((board.player.coords, board.player.orientation)) {
+            return Err(guard_is_trapped());
+        }
+        on_step(board)?;
+    }
+    Ok(())
+}
+
+/// Number of distinct tiles the guard visits before leaving the map.
+pub fn solve_part_1(mut board: SituationMap) -> Result<usize, Error> {
+    patrol(&mut board, |_| Ok(()))?;
+    Ok(board.seen_tiles())
+}
+
+/// The guard, its position and the direction it is facing.
+#[derive(Clone, Debug)]
+pub struct Player {
+    pub orientation: Orientation,
+    pub coords: Coord,
+}
+
+#[derive(PartialEq, Clone, Debug, Hash, Copy, Eq)]
+pub enum Orientation {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Orientation {
+    pub fn rotate_right(&mut self) -> Self {
+        match self {
+            Orientation::Up => Orientation::Right,
+            Orientation::Right => Orientation::Down,
+            Orientation::Down => Orientation::Left,
+            Orientation::Left => Orientation::Up,
+        }
+    }
+
+    /// The grid direction the guard walks in.
+    pub fn direction(self) -> Direction {
+        match self {
+            Orientation::Up => Direction::Up,
+            Orientation::Right => Direction::Right,
+            Orientation::Down => Direction::Down,
+            Orientation::Left => Direction::Left,
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Copy, Debug)]
+pub enum MapElements {
+    Free,
+    PrevouslySeen,
+    Obstructed,
+}
+
+/// The lab map with the guard on it, parsed from `.` (free), `#` (obstructed) and one of
+/// `^>v<` for the guard.
+#[derive(Clone)]
+pub struct SituationMap {
+    player: Player,
+    map: Grid<MapElements>,
+}
+
+impl SituationMap {
+    pub fn player(&self) -> &Player {
+        &self.player
+    }
+
+    pub fn width(&self) -> usize {
+        self.map.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.map.height()
+    }
+
+    pub fn map(&self) -> &Grid<MapElements> {
+        &self.map
+    }
+
+    /// Places an obstruction in front of a virtual guard at `starting_at` and walks it until it
+    /// either leaves the map or runs in a circle. Returns the location of the obstruction in the
+    /// latter case. The map is left unchanged.
+    pub fn test_circular_path(
+        &mut self,
+        starting_at: Coord,
+        orientation: Orientation,
+    ) -> Option<Coord> {
+        let mut virtual_player = Player {
+            coords: starting_at,
+            orientation,
+        };
+
+        let mut visited_tiles: HashSet<(Coord, Orientation)> = HashSet::new();
+        let (old_location, &old_tile) = self.what_is_in_front(&virtual_player)?;
+
+        self.set_at(&old_location, MapElements::Obstructed);
+
+        loop {
+            match self.what_is_in_front(&virtual_player) {
+                Some((coord, element)) => match element {
+                    MapElements::Free | MapElements::PrevouslySeen => {
+                        virtual_player.coords = coord;
+                    }
+                    MapElements::Obstructed => {
+                        virtual_player.orientation = virtual_player.orientation.rotate_right();
+
+                        if !visited_tiles
+                            .insert((virtual_player.coords, virtual_player.orientation))
+                        {
+                            self.set_at(&old_location, old_tile);
+                            return Some(old_location);
+                        }
+                    }
+                },
+                None => {
+                    self.set_at(&old_location, old_tile);
+                    return None;
+                }
+            };
+        }
+    }
+
+    /// The element at `coord`, `None` outside the map.
+    pub fn what_is_at(&self, coord: &Coord) -> Option<&MapElements> {
+        self.map.get(*coord)
+    }
+
+    /// Replaces the element at `coord`, which has to be on the map.
+    pub fn set_at(&mut self, coord: &Coord, element: MapElements) {
+        self.map[*coord] = element;
+    }
+
+    /// Number of tiles the guard has visited so far.
+    pub fn seen_tiles(&self) -> usize {
+        self.map
+            .cells()
+            .iter()
+            .filter(|&t| t == &MapElements::PrevouslySeen)
+            .count()
+    }
+
+    /// Location and element of the tile `player` is facing, `None` when it faces the edge.
+    pub fn what_is_in_front(&self, player: &Player) -> Option<(Coord, &MapElements)> {
+        let coords_in_front = self
+            .map
+            .step(player.coords, player.orientation.direction())?;
+
+        Some((coords_in_front, &self.map[coords_in_front]))
+    }
+
+    /// Moves the guard one tile forward, or turns it right when it faces an obstruction. Returns
+    /// the new position of the guard, or `None` once it walked off the map.
+    pub fn step(&mut self) -> Option<&Coord> {
+        match self.what_is_in_front(&self.player) {
+            Some((coord, element)) => match element {
+                MapElements::Free | MapElements::PrevouslySeen => {
+                    self.player.coords = coord;
+                    self.set_at(&coord, MapElements::PrevouslySeen);
+                    Some(&self.player.coords)
+                }
+                MapElements::Obstructed => {
+                    self.player.orientation = self.player.orientation.rotate_right();
+                    Some(&self.player.coords)
+                }
+            },
+            // We went out of bounds, the desired end state.
+            None => None,
+        }
+    }
+}
+
+/// Draws the map with ANSI colours, see [`viz::frame`].
+impl Display for SituationMap {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", viz::frame(self, &HashSet::new(), None))
+    }
+}
+
+impl TryFrom<&str> for SituationMap {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut player: Option<Player> = None;
+
+        let map = Grid::parse(value, |coords, c| {
+            let orientation = match c {
+                '.' => return Ok(MapElements::Free),
+                '#' => return Ok(MapElements::Obstructed),
+                '^' => Orientation::Up,
+                '>' => Orientation::Right,
+                'v' => Orientation::Down,
+                '<' => Orientation::Left,
+                _ => return Err(String::from("expected one of `.#^>v<`")),
+            };
+
+            if let Some(p) = &player {
+                return Err(format!(
+                    "duplicate guard, the first one is at line {}, column {}",
+                    p.coords.row + 1,
+                    p.coords.col + 1
+                ));
+            }
+
+            player = Some(Player {
+                coords,
+                orientation,
+            });
+            Ok(MapElements::PrevouslySeen)
+        })?;
+
+        match player {
+            Some(player) => Ok(SituationMap { player, map }),
+            None => Err(ParseError::new(1, 1, "", "map does not contain a guard")),
+        }
+    }
+}
+
+/// The option under `[day06]` in `aoc.toml` that `--display-solution` defaults to.
+pub const DISPLAY_SOLUTION: &str = "display-solution";
+
+/// [`Solution`] for day 6.
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const OPTIONS: &'static [&'static str] = &[DISPLAY_SOLUTION];
+
+    type Input = SituationMap;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(SituationMap::try_from(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer, Error> {
+        solve_part_1(input.clone())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer, Error> {
+        solve_part_2(input.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_readme_examples() {
+        aoc_core::examples::assert_examples::<Day06>(include_str!("../readme.md"));
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut board = SituationMap::try_from(
+            "....
+....
+..^.
+..#.",
+        )
+        .unwrap();
+        assert_eq!(board.height(), 4);
+        assert_eq!(board.width(), 4);
+
+        let coords = Coord { col: 0, row: 0 };
+        board.set_at(&coords, MapElements::Obstructed);
+        println!("{board}");
+
+        assert!(board
+            .what_is_at(&coords)
+            .is_some_and(|e| *e == MapElements::Obstructed));
+
+        let coords = Coord { col: 3, row: 3 };
+        board.set_at(&coords, MapElements::Obstructed);
+        println!("{board}");
+
+        assert!(board
+            .what_is_at(&coords)
+            .is_some_and(|e| *e == MapElements::Obstructed));
+
+        assert!(board
+            .what_is_in_front(&board.player)
+            .is_some_and(|(_, e)| *e == MapElements::Free));
+
+        board.player.orientation = Orientation::Down;
+
+        assert!(board
+            .what_is_in_front(&board.player)
+            .is_some_and(|(_, e)| *e == MapElements::Obstructed));
+    }
+
+    #[test]
+    fn test_circular_path_detection() {
+        let boards = [".............
+...........#.
+#v..........#
+.#.........#."];
+
+        for board in boards {
+            println!("{board}");
+            let parsed_board = SituationMap::try_from(board).unwrap();
+            println!("{parsed_board}");
+            assert_eq!(solve_part_2(parsed_board).unwrap(), 1);
+        }
+    }
+
+    #[test]
+    fn test_obstruction_in_front_of_start() {
+        let board = SituationMap::try_from("...#\n.#^#\n..#.\n.###").unwrap();
+        assert_eq!(solve_part_2(board).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_detection_near_edges() {
+        let boards = [
+            "#<..
+....
+....
+....",
+            "#...
+^...
+....
+....",
+            "..>#
+....
+....
+....",
+            "...#
+...^
+....
+....",
+            "....
+....
+....
+#<..",
+            "....
+....
+v...
+#...",
+            "....
+....
+....
+..>#",
+            "....
+....
+...v
+...#",
+        ];
+        for board in boards {
+            let parsed_board = SituationMap::try_from(board).unwrap();
+            match parsed_board.what_is_in_front(&parsed_board.player) {
+                Some((_, element)) => assert_eq!(element, &MapElements::Obstructed),
+                _ => panic!("Failed to get expected element."),
+            }
+        }
+    }
+
+    #[test]
+    fn test_trapped_guard() {
+        let boards = [".#.\n#^#\n.#.", ".#..\n...#\n#^..\n..#."];
+
+        for board in boards {
+            let parsed_board = SituationMap::try_from(board).unwrap();
+            assert!(matches!(
+                solve_part_1(parsed_board.clone()),
+                Err(Error::Unsolvable(_))
+            ));
+            assert!(matches!(
+                solve_part_2(parsed_board),
+                Err(Error::Unsolvable(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_parse_error_location() {
+        let error = SituationMap::try_from("....\n.^.^\n....").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 4));
+
+        let error = SituationMap::try_from("....\n.^.\n....").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 1));
+
+        let error = SituationMap::try_from("....\n.^.x").err().unwrap();
+        assert_eq!(
+            error,
+            ParseError::new(2, 4, "x", "expected one of `.#^>v<`")
+        );
+    }
+}
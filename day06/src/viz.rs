@@ -0,0 +1,151 @@
+//! Animations of the guard's patrol and of the search for obstructions that trap it.
+
+use std::collections::HashSet;
+
+use aoc_core::Error;
+use aoc_viz::{Cell, Frame, Rgb, Sink};
+
+use crate::{find_traps, patrol, Coord, MapElements, Orientation, SituationMap};
+
+const FREE: Rgb = Rgb::GREEN;
+const SEEN: Rgb = Rgb(41, 184, 219);
+const OBSTRUCTED: Rgb = Rgb::BLACK;
+const GUARD: Rgb = Rgb::RED;
+/// The new obstruction being tried.
+const TESTED: Rgb = Rgb::YELLOW;
+/// New obstructions found to trap the guard.
+const TRAP: Rgb = Rgb::MAGENTA;
+
+/// The map with the guard, the tiles it visited, the new obstructions in `traps` and the one
+/// being tried at `tested`.
+pub fn frame(board: &SituationMap, traps: &HashSet<Coord>, tested: Option<Coord>) -> Frame {
+    Frame::render(&board.map, |coord, element| {
+        if coord == board.player.coords {
+            let glyph = match board.player.orientation {
+                Orientation::Up => '^',
+                Orientation::Right => '>',
+                Orientation::Down => 'V',
+                Orientation::Left => '<',
+            };
+            return Cell::new(glyph, GUARD, SEEN);
+        }
+        if tested == Some(coord) {
+            return Cell::new('O', Rgb::BLACK, TESTED);
+        }
+        if traps.contains(&coord) {
+            return Cell::new('O', Rgb::WHITE, TRAP);
+        }
+
+        match element {
+            MapElements::Free => Cell::blank(FREE),
+            MapElements::PrevouslySeen => Cell::blank(SEEN),
+            MapElements::Obstructed => Cell::blank(OBSTRUCTED),
+        }
+    })
+}
+
+/// Sends a frame for the start and every step of the guard's patrol to `sink`.
+pub fn walk(mut board: SituationMap, sink: &mut dyn Sink) -> Result<(), Error> {
+    let start = board.player.coords;
+    let caption = format!("Starting at {}, {}", start.row, start.col);
+    sink.frame(&frame(&board, &HashSet::new(), None).with_caption(caption))
+        .map_err(Error::Output)?;
+
+    patrol(&mut board, |board| {
+        let coords = board.player.coords;
+        let caption = format!("Stepped to {}, {}", coords.row, coords.col);
+        sink.frame(&frame(board, &HashSet::new(), None).with_caption(caption))
+            .map_err(Error::Output)
+    })
+}
+
+/// Sends a frame for every new obstruction tried along the guard's patrol to `sink`, marking
+/// the ones that trap the guard.
+pub fn obstructions(board: SituationMap, sink: &mut dyn Sink) -> Result<(), Error> {
+    find_traps(board, |board, tested, traps| {
+        let caption = format!(
+            "Trying {}, {}: {} obstructions trap the guard",
+            tested.row,
+            tested.col,
+            traps.len()
+        );
+        sink.frame(&frame(board, traps, Some(tested)).with_caption(caption))
+            .map_err(Error::Output)
+    })?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct Frames(Vec<Frame>);
+
+    impl Sink for Frames {
+        fn frame(&mut self, frame: &Frame) -> io::Result<()> {
+            self.0.push(frame.clone());
+            Ok(())
+        }
+    }
+
+    fn glyphs(frame: &Frame) -> Vec<String> {
+        let glyph = |cell: &Cell| if cell.glyph == ' ' { '.' } else { cell.glyph };
+        frame
+            .cells
+            .rows()
+            .map(|row| row.iter().map(glyph).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_walk() {
+        let board = SituationMap::try_from("#...\n....\n.#..\n^...").unwrap();
+        let mut frames = Frames::default();
+        walk(board, &mut frames).unwrap();
+
+        // The start, two steps up, a turn and three steps right.
+        assert_eq!(frames.0.len(), 7);
+        assert_eq!(frames.0[0].caption, "Starting at 3, 0");
+        assert_eq!(frames.0[3].caption, "Stepped to 1, 0");
+        assert_eq!(glyphs(&frames.0[3]), ["....", ">...", "....", "...."]);
+        assert_eq!(frames.0[3].cells[Coord::new(1, 1)].bg, FREE);
+        assert_eq!(frames.0[3].cells[Coord::new(2, 0)].bg, SEEN);
+    }
+
+    #[test]
+    fn test_frame_marks_obstructions() {
+        let board = SituationMap::try_from("#...\n....\n.#..\n^...").unwrap();
+        let traps = HashSet::from([Coord::new(0, 3), Coord::new(3, 0)]);
+        let frame = frame(&board, &traps, Some(Coord::new(2, 0)));
+
+        let bg = |row, col| frame.cells[Coord::new(row, col)].bg;
+        assert_eq!(glyphs(&frame), ["...O", "....", "O...", "^..."]);
+        assert_eq!((bg(0, 3), bg(2, 0), bg(3, 0)), (TRAP, TESTED, SEEN));
+        assert_eq!((bg(0, 0), bg(0, 1)), (OBSTRUCTED, FREE));
+    }
+
+    #[test]
+    fn test_obstructions() {
+        let board =
+            SituationMap::try_from(".............\n...........#.\n#v..........#\n.#.........#.")
+                .unwrap();
+        let mut frames = Frames::default();
+        obstructions(board, &mut frames).unwrap();
+
+        let captions: Vec<&str> = frames.0.iter().map(|f| f.caption.as_str()).collect();
+        assert_eq!(
+            captions[captions.len() - 2..],
+            [
+                "Trying 1, 1: 1 obstructions trap the guard",
+                "Trying 0, 1: 1 obstructions trap the guard"
+            ]
+        );
+        assert_eq!(
+            frames.0[captions.len() - 2].cells[Coord::new(1, 1)].bg,
+            TESTED
+        );
+    }
+}
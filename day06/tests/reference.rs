@@ -0,0 +1,87 @@
+//! Compares the solver against a slow but obviously correct reference on generated inputs.
+
+use std::collections::HashSet;
+
+use aoc_core::examples::assert_examples;
+use aoc_core::{solve, Error, Solution};
+use day06::Day06;
+use proptest::prelude::*;
+
+/// Walks the guard step by step, and tries a new obstruction on every free position.
+struct Reference;
+
+type Map = Vec<Vec<char>>;
+
+/// The positions the guard visits before leaving `map`, `None` if they walk in circles.
+fn walk(map: &Map, start: (usize, usize)) -> Option<HashSet<(usize, usize)>> {
+    let ((mut row, mut col), (mut dr, mut dc)) = (start, (-1isize, 0isize));
+    let mut states = HashSet::new();
+
+    while states.insert((row, col, dr, dc)) {
+        let next_row = row.checked_add_signed(dr).filter(|&r| r < map.len());
+        let next_col = col.checked_add_signed(dc).filter(|&c| c < map[0].len());
+        let (Some(next_row), Some(next_col)) = (next_row, next_col) else {
+            return Some(states.into_iter().map(|(r, c, _, _)| (r, c)).collect());
+        };
+
+        if map[next_row][next_col] == '#' {
+            (dr, dc) = (dc, -dr);
+        } else {
+            (row, col) = (next_row, next_col);
+        }
+    }
+    None
+}
+
+impl Solution for Reference {
+    const DAY: u8 = 6;
+
+    type Input = (Map, (usize, usize));
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let map: Map = input.lines().map(|line| line.chars().collect()).collect();
+        let start = (0..map.len())
+            .flat_map(|row| (0..map[row].len()).map(move |col| (row, col)))
+            .find(|&(row, col)| map[row][col] == '^')
+            .unwrap();
+
+        Ok((map, start))
+    }
+
+    fn part1((map, start): &Self::Input) -> Result<usize, Error> {
+        Ok(walk(map, *start).unwrap().len())
+    }
+
+    fn part2((map, start): &Self::Input) -> Result<usize, Error> {
+        let mut loops = 0;
+        for row in 0..map.len() {
+            for col in 0..map[row].len() {
+                if map[row][col] == '.' {
+                    let mut blocked = map.clone();
+                    blocked[row][col] = '#';
+                    loops += usize::from(walk(&blocked, *start).is_none());
+                }
+            }
+        }
+        Ok(loops)
+    }
+}
+
+#[test]
+fn test_reference_readme_examples() {
+    assert_examples::<Reference>(include_str!("../readme.md"));
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn test_matches_reference(seed: u64, size in 1..30usize) {
+        let input = aoc_gen::generate(6, size, seed).unwrap();
+        let expected = solve::<Reference>(&input).unwrap();
+        let actual = solve::<Day06>(&input).unwrap();
+
+        prop_assert_eq!((actual.part_1, actual.part_2), (expected.part_1, expected.part_2));
+    }
+}
@@ -0,0 +1,209 @@
+//! Day 7: Bridge Repair.
+//!
+//! Finds the calibration equations that can be made true by inserting operators between their
+//! numbers, evaluated left to right.
+//!
+//! ```
+//! let equations = day07::parse_input("190: 10 19\n156: 15 6").unwrap();
+//!
+//! assert_eq!(day07::sum_solvable(&equations, false).unwrap(), 190);
+//! // Concatenating 15 and 6 gives 156.
+//! assert_eq!(day07::sum_solvable(&equations, true).unwrap(), 346);
+//! ```
+
+use aoc_core::num::{checked_add, Int};
+use aoc_core::{Error, ParseError, Solution};
+use aoc_parse::{key_values, lines, number, number_list};
+use nom::character::complete::char;
+use nom::combinator::map;
+use nom::IResult;
+
+/// Sum of the test values that can be made with `+`, `*` and `||`.
+pub fn solve_part_2(calibration_doc: &str) -> Result<Int, Error> {
+    let equations = parse_input(calibration_doc)?;
+    sum_solvable(&equations, true)
+}
+
+/// Sum of the test values that can be made with `+` and `*`.
+pub fn solve_part_1(calibration_doc: &str) -> Result<Int, Error> {
+    let equations = parse_input(calibration_doc)?;
+    sum_solvable(&equations, false)
+}
+
+/// Sum of the test values of all solvable equations. With `do_the_funny` the concatenation
+/// operator `||` may be used as well.
+pub fn sum_solvable(equations: &[CalibrationEquation], do_the_funny: bool) -> Result<Int, Error> {
+    let mut sum = 0;
+    for cal in equations {
+        // The operators go between the numbers, so the first one is where the result starts.
+        let Some((&first, rest)) = cal.calibration_vectors.split_first() else {
+            continue;
+        };
+        if calculate_recursively(first, cal.calibration_sum, rest, do_the_funny) {
+            sum = checked_add(sum, cal.calibration_sum, "the total calibration result")?;
+        }
+    }
+    Ok(sum)
+}
+
+/// Parses one `test value: numbers` equation per line.
+pub fn parse_input(calibration_doc: &str) -> Result<Vec<CalibrationEquation>, ParseError> {
+    lines(
+        calibration_doc,
+        parse_input_line,
+        "expected an equation like 190: 10 19",
+    )
+}
+
+/// A test value and the numbers that should produce it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalibrationEquation {
+    pub calibration_sum: Int,
+    pub calibration_vectors: Vec<Int>,
+}
+
+/// Parses a single `test value: numbers` equation.
+pub fn parse_input_line(input: &str) -> IResult<&str, CalibrationEquation> {
+    map(
+        key_values(number, number_list(char(' '))),
+        |(calibration_sum, calibration_vectors)| CalibrationEquation {
+            calibration_sum,
+            calibration_vectors,
+        },
+    )(input)
+}
+
+/// Whether the numbers in `remainder` can be combined onto `sum` to reach exactly `limit`.
+pub fn calculate_recursively(sum: Int, limit: Int, remainder: &[Int], do_the_funny: bool) -> bool {
+    // In case of do_the_funny, we my take the second to last and last number.
+    // To make logic handling easier, just check if we still have a remaining number.
+    if remainder.is_empty() {
+        return sum == limit;
+    }
+
+    // A result that does not fit in an Int is larger than any limit, so overflowing branches
+    // are pruned like any other branch that overshoots.
+    let multiply_res = sum.checked_mul(remainder[0]);
+    let addition_res = sum.checked_add(remainder[0]);
+    let mut funny_number: Option<_> = None;
+
+    if do_the_funny {
+        // Could do some log10 math here, multiply the sum, add the next number etc.
+        // lets save optimizations for harder problems.
+        funny_number = format!("{sum}{}", remainder[0]).parse::<Int>().ok();
+    }
+
+    // This used to be a return (branch1 || branch2 || branch3) but I think that became unreadable
+    // Compiler will figure it out anyways :P
+    if multiply_res.is_some_and(|m| {
+        limit >= m && calculate_recursively(m, limit, &remainder[1..], do_the_funny)
+    }) {
+        return true;
+    }
+    if addition_res.is_some_and(|a| {
+        limit >= a && calculate_recursively(a, limit, &remainder[1..], do_the_funny)
+    }) {
+        return true;
+    }
+    if funny_number.is_some_and(|f| {
+        limit >= f && calculate_recursively(f, limit, &remainder[1..], do_the_funny)
+    }) {
+        return true;
+    }
+    false
+}
+
+/// [`Solution`] for day 7.
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Vec<CalibrationEquation>;
+    type Answer = Int;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer, Error> {
+        sum_solvable(input, false)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer, Error> {
+        sum_solvable(input, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_readme_examples() {
+        aoc_core::examples::assert_examples::<Day07>(include_str!("../readme.md"));
+    }
+
+    #[test]
+    fn test_calculate_recursively() {
+        let inputs: [(Int, &[Int]); 9] = [
+            (190, &[10, 19]),
+            (3267, &[81, 40, 27]),
+            (83, &[17, 5]),
+            (156, &[15, 6]),
+            (7290, &[6, 8, 6, 15]),
+            (161011, &[16, 10, 13]),
+            (192, &[17, 8, 14]),
+            (21037, &[9, 7, 18, 13]),
+            (292, &[11, 6, 16, 20]),
+        ];
+
+        let expected_sum = 3749;
+        let mut actual_sum = 0;
+
+        for (limit, factors) in inputs {
+            if calculate_recursively(factors[0], limit, &factors[1..], false) {
+                actual_sum += limit;
+            }
+        }
+        assert_eq!(actual_sum, expected_sum)
+    }
+
+    #[test]
+    fn test_overflowing_branches() {
+        assert!(!calculate_recursively(Int::MAX, Int::MAX, &[2], true));
+        assert!(calculate_recursively(Int::MAX, Int::MAX, &[1, 0], true));
+    }
+
+    #[test]
+    fn test_first_number_is_kept() {
+        // 2 * 3 makes 6, but only when the 5 in front of them is dropped.
+        let equations = parse_input(
+            "6: 5 2 3
+10: 5 2",
+        )
+        .unwrap();
+        assert_eq!(sum_solvable(&equations, false).unwrap(), 10);
+        assert_eq!(sum_solvable(&equations, true).unwrap(), 10);
+    }
+
+    #[test]
+    fn test_sum_overflow() {
+        let equations = parse_input(&format!("{0}: {0}\n{0}: {0}", Int::MAX)).unwrap();
+        assert!(matches!(
+            sum_solvable(&equations, false),
+            Err(Error::Overflow(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_error_location() {
+        let error = parse_input("190: 10 19\n3267: 81 40 x27").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 13));
+
+        let error = parse_input("190: 10 999999999999999999999999999999999999999999")
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (1, 9));
+    }
+}
@@ -0,0 +1,78 @@
+//! Compares the solver against a slow but obviously correct reference on generated inputs.
+
+use aoc_core::examples::assert_examples;
+use aoc_core::{solve, Error, Solution};
+use day07::Day07;
+use proptest::prelude::*;
+
+/// Evaluates every combination of operators, without pruning.
+struct Reference;
+
+/// Whether some choice of `operators` between `numbers` gives `value`.
+fn solvable(value: u128, numbers: &[u128], operators: u32) -> bool {
+    (0..operators.pow(numbers.len() as u32 - 1)).any(|mut choice| {
+        let mut result = numbers[0];
+        for &number in &numbers[1..] {
+            result = match choice % operators {
+                0 => result + number,
+                1 => result * number,
+                _ => format!("{result}{number}").parse().unwrap(),
+            };
+            choice /= operators;
+        }
+        result == value
+    })
+}
+
+fn sum_solvable(equations: &[(u128, Vec<u128>)], operators: u32) -> usize {
+    let sum: u128 = equations
+        .iter()
+        .filter(|(value, numbers)| solvable(*value, numbers, operators))
+        .map(|(value, _)| value)
+        .sum();
+    sum.try_into().unwrap()
+}
+
+impl Solution for Reference {
+    const DAY: u8 = 7;
+
+    type Input = Vec<(u128, Vec<u128>)>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(input
+            .lines()
+            .map(|line| {
+                let (value, numbers) = line.split_once(": ").unwrap();
+                let numbers = numbers.split(' ').map(|n| n.parse().unwrap()).collect();
+                (value.parse().unwrap(), numbers)
+            })
+            .collect())
+    }
+
+    fn part1(equations: &Self::Input) -> Result<usize, Error> {
+        Ok(sum_solvable(equations, 2))
+    }
+
+    fn part2(equations: &Self::Input) -> Result<usize, Error> {
+        Ok(sum_solvable(equations, 3))
+    }
+}
+
+#[test]
+fn test_reference_readme_examples() {
+    assert_examples::<Reference>(include_str!("../readme.md"));
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn test_matches_reference(seed: u64, size in 1..20usize) {
+        let input = aoc_gen::generate(7, size, seed).unwrap();
+        let expected = solve::<Reference>(&input).unwrap();
+        let actual = solve::<Day07>(&input).unwrap();
+
+        prop_assert_eq!((actual.part_1, actual.part_2), (expected.part_1, expected.part_2));
+    }
+}
@@ -0,0 +1,18 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+/// Most numbers in an equation of a puzzle input. The solver tries every combination of
+/// operators, so its time grows exponentially with longer equations without that being a bug.
+const MAX_NUMBERS: usize = 12;
+
+fuzz_target!(|input: &str| {
+    // A line holds the test value and its numbers, separated by spaces.
+    if input
+        .lines()
+        .any(|line| line.split(' ').count() > MAX_NUMBERS + 1)
+    {
+        return;
+    }
+    let _ = aoc_core::solve::<day07::Day07>(input);
+});